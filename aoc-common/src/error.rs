@@ -0,0 +1,42 @@
+use std::num::ParseIntError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("could not read input {input}")]
+    Io {
+        input: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error(transparent)]
+    ParseInt(#[from] ParseIntError),
+
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+
+    #[error("invalid input: {0}")]
+    Invalid(String),
+}
+
+/// Input that doesn't parse, pointing at where it went wrong
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("day {day}, line {line}, column {column}: {reason}: {text:?}")]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based
+    pub line: usize,
+    /// 1-based, in chars
+    pub column: usize,
+    /// the offending part of the line
+    pub text: String,
+    pub reason: String,
+}
+
+impl Error {
+    pub fn invalid(msg: impl Into<String>) -> Error {
+        Error::Invalid(msg.into())
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
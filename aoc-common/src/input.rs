@@ -0,0 +1,221 @@
+use std::fs;
+use std::io::BufRead;
+use std::path::Path;
+
+use crate::{Error, ParseError, Result};
+
+/// Read the whole input file into a String
+pub fn read_input(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|source| Error::Io {
+        input: path.display().to_string(),
+        source,
+    })
+}
+
+/// Lines of the input, without the trailing newline (`\n` or `\r\n`)
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines()
+}
+
+/// A line of input that knows where it came from, so parse errors can
+/// point at it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputLine<'a> {
+    pub day: u8,
+    /// 1-based
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> InputLine<'a> {
+    pub fn new(day: u8, number: usize, text: &'a str) -> InputLine<'a> {
+        InputLine { day, number, text }
+    }
+
+    /// Error about the whole line
+    pub fn error(&self, reason: impl Into<String>) -> Error {
+        self.error_at(self.text, reason)
+    }
+
+    /// Error about `part`, a slice of this line, e.g. one of its tokens.
+    ///
+    /// Anything that isn't borrowed from the line is reported at column 1.
+    pub fn error_at(&self, part: &str, reason: impl Into<String>) -> Error {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|offset| offset + part.len() <= self.text.len());
+        let column = offset
+            .and_then(|offset| self.text.get(..offset))
+            .map_or(1, |before| before.chars().count() + 1);
+
+        Error::Parse(ParseError {
+            day: self.day,
+            line: self.number,
+            column,
+            text: part.to_string(),
+            reason: reason.into(),
+        })
+    }
+}
+
+/// Lines of the input, numbered from 1
+pub fn numbered_lines(day: u8, input: &str) -> impl Iterator<Item = InputLine<'_>> {
+    lines(input)
+        .enumerate()
+        .map(move |(index, text)| InputLine::new(day, index + 1, text))
+}
+
+/// Feed the lines of `reader` to `f` one at a time, numbered from 1.
+///
+/// Only a single line is held in memory, so this works on inputs far bigger
+/// than RAM. Line endings are stripped like [`lines`] does.
+pub fn for_each_line<R: BufRead>(
+    day: u8,
+    mut reader: R,
+    mut f: impl FnMut(InputLine) -> Result<()>,
+) -> Result<()> {
+    let mut buf = String::new();
+    let mut number = 0;
+
+    loop {
+        buf.clear();
+        let read = reader.read_line(&mut buf).map_err(|source| Error::Io {
+            input: format!("day {day}"),
+            source,
+        })?;
+        if read == 0 {
+            return Ok(());
+        }
+        number += 1;
+
+        let text = buf.strip_suffix('\n').unwrap_or(&buf);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        f(InputLine::new(day, number, text))?;
+    }
+}
+
+/// Groups of consecutive non-blank lines, split at blank lines and
+/// numbered like [`numbered_lines`].
+///
+/// Runs of several blank lines and leading / trailing blank lines don't
+/// produce empty paragraphs.
+pub fn paragraphs(day: u8, input: &str) -> Vec<Vec<InputLine<'_>>> {
+    let mut paragraphs = Vec::new();
+    let mut current = Vec::new();
+
+    for line in numbered_lines(day, input) {
+        if line.text.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+    paragraphs
+}
+
+#[cfg(test)]
+mod test_input {
+    use super::*;
+
+    #[test]
+    fn read_missing_file() {
+        let err = read_input("does/not/exist.txt").unwrap_err();
+        assert!(matches!(err, Error::Io { .. }));
+    }
+
+    #[test]
+    fn lines_ok() {
+        let lines: Vec<&str> = lines("a\r\nb\nc").collect();
+        assert_eq!(vec!["a", "b", "c"], lines);
+    }
+
+    #[test]
+    fn error_points_at_token() {
+        let line = InputLine::new(2, 7, "A  Wé X");
+        let token = line.text.split_whitespace().nth(2).unwrap();
+        let Error::Parse(err) = line.error_at(token, "not a valid move") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(7, err.column);
+        assert_eq!(
+            r#"day 2, line 7, column 7: not a valid move: "X""#,
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn error_outside_line() {
+        let line = InputLine::new(1, 1, "abc");
+        let Error::Parse(err) = line.error_at("x", "nope") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(1, err.column);
+    }
+
+    #[test]
+    fn numbered_lines_ok() {
+        let lines: Vec<_> = numbered_lines(3, "a\nb").collect();
+        assert_eq!(InputLine::new(3, 2, "b"), lines[1]);
+    }
+
+    #[test]
+    fn for_each_line_ok() {
+        let mut lines = Vec::new();
+        for_each_line(3, "a\r\n\nb".as_bytes(), |line| {
+            lines.push((line.number, line.text.to_string()));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(
+            vec![(1, "a".into()), (2, "".into()), (3, "b".into())],
+            lines
+        );
+    }
+
+    #[test]
+    fn for_each_line_stops_at_error() {
+        let mut seen = 0;
+        let err = for_each_line(3, "1\nx\n3\n".as_bytes(), |line| {
+            seen += 1;
+            line.text
+                .parse::<u32>()
+                .map(|_| ())
+                .map_err(|_| line.error("nope"))
+        })
+        .unwrap_err();
+        assert_eq!(2, seen);
+        assert!(matches!(err, Error::Parse(ParseError { line: 2, .. })));
+    }
+
+    #[test]
+    fn paragraphs_ok() {
+        let input = "\n1\n2\n\n3\n\n\n4\r\n5\n";
+        let paragraphs: Vec<Vec<(usize, &str)>> = paragraphs(1, input)
+            .iter()
+            .map(|lines| lines.iter().map(|line| (line.number, line.text)).collect())
+            .collect();
+        assert_eq!(
+            vec![
+                vec![(2, "1"), (3, "2")],
+                vec![(5, "3")],
+                vec![(8, "4"), (9, "5")]
+            ],
+            paragraphs
+        );
+    }
+
+    #[test]
+    fn paragraphs_keep_leading_spaces() {
+        let input = "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1";
+        let paragraphs = paragraphs(5, input);
+        assert_eq!(2, paragraphs.len());
+        assert_eq!("    [D]", paragraphs[0][0].text);
+        assert_eq!(InputLine::new(5, 5, "move 1 from 2 to 1"), paragraphs[1][0]);
+    }
+}
@@ -0,0 +1,10 @@
+//! Helpers shared by every day crate: where the input comes from, splitting
+//! it into lines or paragraphs, and the common error type.
+
+mod error;
+mod input;
+mod source;
+
+pub use error::{Error, ParseError, Result};
+pub use input::{for_each_line, lines, numbered_lines, paragraphs, read_input, InputLine};
+pub use source::{InputSource, INPUT_DIR_VAR};
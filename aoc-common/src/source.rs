@@ -0,0 +1,188 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::input::{lines, read_input};
+use crate::{Error, Result};
+
+/// Environment variable naming a directory holding `day1.txt`, `day2.txt`, ...
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a solver gets its puzzle input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// a file on disk
+    Path(PathBuf),
+    /// standard input, read when the solver asks for it
+    Stdin,
+    /// input already in memory, e.g. `include_str!`'d into the binary
+    Text(Cow<'static, str>),
+}
+
+impl InputSource {
+    pub const fn embedded(text: &'static str) -> InputSource {
+        InputSource::Text(Cow::Borrowed(text))
+    }
+
+    /// input built at runtime, e.g. by a generator
+    pub fn text(text: impl Into<String>) -> InputSource {
+        InputSource::Text(Cow::Owned(text.into()))
+    }
+
+    /// `dayN.txt` inside the directory named by `AOC_INPUT_DIR`, if it is set
+    pub fn from_input_dir(day: u8) -> Option<InputSource> {
+        let dir = std::env::var_os(INPUT_DIR_VAR)?;
+        Some(InputSource::in_dir(dir, day))
+    }
+
+    pub fn in_dir(dir: impl AsRef<Path>, day: u8) -> InputSource {
+        InputSource::Path(dir.as_ref().join(format!("day{day}.txt")))
+    }
+
+    /// The whole input as a String
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::Path(path) => read_input(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| Error::Io {
+                        input: self.to_string(),
+                        source,
+                    })?;
+                Ok(input)
+            }
+            InputSource::Text(text) => Ok(text.to_string()),
+        }
+    }
+
+    /// The input as owned lines
+    pub fn read_lines(&self) -> Result<Vec<String>> {
+        let input = self.read()?;
+        Ok(lines(&input).map(str::to_string).collect())
+    }
+
+    /// The input as a buffered reader, for solvers that stream it line by line
+    pub fn reader(&self) -> Result<Box<dyn BufRead + '_>> {
+        match self {
+            InputSource::Path(path) => {
+                let file = File::open(path).map_err(|source| Error::Io {
+                    input: self.to_string(),
+                    source,
+                })?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            InputSource::Stdin => Ok(Box::new(std::io::stdin().lock())),
+            InputSource::Text(text) => Ok(Box::new(text.as_bytes())),
+        }
+    }
+
+    /// Read the input now, so it can be solved more than once.
+    ///
+    /// Stdin can only be consumed a single time, and loading up front
+    /// keeps file access out of the solver timings.
+    pub fn load(self) -> Result<InputSource> {
+        match self {
+            InputSource::Text(_) => Ok(self),
+            _ => Ok(InputSource::Text(Cow::Owned(self.read()?))),
+        }
+    }
+}
+
+/// `-` is stdin, anything else a path
+impl FromStr for InputSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::Path(path.into())),
+        }
+    }
+}
+
+impl From<PathBuf> for InputSource {
+    fn from(path: PathBuf) -> Self {
+        InputSource::Path(path)
+    }
+}
+
+impl From<&Path> for InputSource {
+    fn from(path: &Path) -> Self {
+        InputSource::Path(path.to_path_buf())
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Text(_) => write!(f, "<embedded>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_source {
+    use super::*;
+
+    #[test]
+    fn read_embedded() {
+        let input = InputSource::embedded("1\n2\n");
+        assert_eq!("1\n2\n", input.read().unwrap());
+        assert_eq!(vec!["1", "2"], input.read_lines().unwrap());
+    }
+
+    #[test]
+    fn read_path() {
+        let input = InputSource::from(Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/Cargo.toml"
+        )));
+        assert!(input.read().unwrap().contains("aoc-common"));
+    }
+
+    #[test]
+    fn reader_ok() {
+        let input = InputSource::embedded("1\n2\n");
+        let lines: Vec<String> = input
+            .reader()
+            .unwrap()
+            .lines()
+            .map(|l| l.unwrap())
+            .collect();
+        assert_eq!(vec!["1", "2"], lines);
+
+        let input = InputSource::Path("does/not/exist.txt".into());
+        assert!(matches!(input.reader(), Err(Error::Io { .. })));
+    }
+
+    #[test]
+    fn parse_from_str() {
+        assert_eq!(InputSource::Stdin, "-".parse().unwrap());
+        assert_eq!(
+            InputSource::Path("day1.txt".into()),
+            "day1.txt".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn input_dir_convention() {
+        assert_eq!(
+            InputSource::Path("inputs/day7.txt".into()),
+            InputSource::in_dir("inputs", 7)
+        );
+    }
+
+    #[test]
+    fn load_missing_file() {
+        let input = InputSource::Path("does/not/exist.txt".into());
+        let err = input.load().unwrap_err();
+        assert_eq!("could not read input does/not/exist.txt", err.to_string());
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use aoc_common::InputSource;
+use color_eyre::eyre::WrapErr;
+use serde::Deserialize;
+
+use crate::solution::Solution;
+
+/// Which of a day's inputs an answer belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Demo,
+    Input,
+}
+
+impl InputKind {
+    pub const ALL: [InputKind; 2] = [InputKind::Demo, InputKind::Input];
+
+    pub fn source(self, solution: &dyn Solution) -> InputSource {
+        match self {
+            InputKind::Demo => solution.demo_input(),
+            InputKind::Input => solution.default_input(),
+        }
+    }
+}
+
+impl fmt::Display for InputKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputKind::Demo => write!(f, "demo"),
+            InputKind::Input => write!(f, "input"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PartAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    demo: Option<PartAnswers>,
+    input: Option<PartAnswers>,
+}
+
+/// Expected answers, keyed by `dayN`, see `answers.toml`
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+impl Answers {
+    const BUNDLED: &'static str = include_str!("../answers.toml");
+
+    pub fn bundled() -> Answers {
+        Answers::parse(Answers::BUNDLED).expect("answers.toml is valid")
+    }
+
+    pub fn parse(text: &str) -> color_eyre::Result<Answers> {
+        Ok(toml::from_str(text)?)
+    }
+
+    pub fn load(path: &Path) -> color_eyre::Result<Answers> {
+        let text = aoc_common::read_input(path)?;
+        Answers::parse(&text).wrap_err_with(|| format!("invalid answers file {path:?}"))
+    }
+
+    pub fn expected(&self, day: u8, kind: InputKind, part: u8) -> Option<&str> {
+        let day = self.0.get(&format!("day{day}"))?;
+        let parts = match kind {
+            InputKind::Demo => day.demo.as_ref(),
+            InputKind::Input => day.input.as_ref(),
+        }?;
+        match part {
+            1 => parts.part1.as_deref(),
+            2 => parts.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_answers {
+    use super::*;
+
+    #[test]
+    fn bundled_answers_parse() {
+        let answers = Answers::bundled();
+        assert_eq!(Some("24000"), answers.expected(1, InputKind::Demo, 1));
+        assert_eq!(Some("RGLVRCQSB"), answers.expected(5, InputKind::Input, 2));
+        assert_eq!(None, answers.expected(25, InputKind::Input, 1));
+    }
+
+    #[test]
+    fn unknown_fields_rejected() {
+        assert!(Answers::parse("[day1.demo]\npart3 = \"1\"").is_err());
+    }
+}
@@ -0,0 +1,23 @@
+mod day1;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+
+use crate::solution::Solution;
+
+pub const ALL: &[&dyn Solution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+];
+
+pub fn get(day: u8) -> Option<&'static dyn Solution> {
+    ALL.iter().copied().find(|solution| solution.day() == day)
+}
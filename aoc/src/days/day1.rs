@@ -0,0 +1,41 @@
+use std::io::BufRead;
+
+use aoc_common::InputSource;
+
+use crate::solution::Solution;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn default_input(&self) -> InputSource {
+        day1::INPUT
+    }
+
+    fn demo_input(&self) -> InputSource {
+        day1::DEMO
+    }
+
+    fn part1(&self, input: &InputSource) -> aoc_common::Result<String> {
+        Ok(day1::max_calories(input)?.to_string())
+    }
+
+    fn part2(&self, input: &InputSource) -> aoc_common::Result<String> {
+        Ok(day1::top_3_calories(input)?.to_string())
+    }
+
+    fn stream_part1(&self, reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        Ok(day1::stream::max_calories(reader)?.to_string())
+    }
+
+    fn stream_part2(&self, reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        Ok(day1::stream::top_3_calories(reader)?.to_string())
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        day1::gen::generate(seed, size)
+    }
+}
@@ -0,0 +1,41 @@
+use std::io::BufRead;
+
+use aoc_common::InputSource;
+
+use crate::solution::Solution;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn default_input(&self) -> InputSource {
+        day2::INPUT
+    }
+
+    fn demo_input(&self) -> InputSource {
+        day2::DEMO
+    }
+
+    fn part1(&self, input: &InputSource) -> aoc_common::Result<String> {
+        Ok(day2::total_score(input, day2::ParseMode::Moves)?.to_string())
+    }
+
+    fn part2(&self, input: &InputSource) -> aoc_common::Result<String> {
+        Ok(day2::total_score(input, day2::ParseMode::Outcomes)?.to_string())
+    }
+
+    fn stream_part1(&self, reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        Ok(day2::stream::total_score(reader, day2::ParseMode::Moves)?.to_string())
+    }
+
+    fn stream_part2(&self, reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        Ok(day2::stream::total_score(reader, day2::ParseMode::Outcomes)?.to_string())
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        day2::gen::generate(seed, size)
+    }
+}
@@ -0,0 +1,41 @@
+use std::io::BufRead;
+
+use aoc_common::InputSource;
+
+use crate::solution::Solution;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn default_input(&self) -> InputSource {
+        day3::INPUT
+    }
+
+    fn demo_input(&self) -> InputSource {
+        day3::DEMO
+    }
+
+    fn part1(&self, input: &InputSource) -> aoc_common::Result<String> {
+        Ok(day3::total_priority_sum(input)?.to_string())
+    }
+
+    fn part2(&self, input: &InputSource) -> aoc_common::Result<String> {
+        Ok(day3::get_badges_sum(input)?.to_string())
+    }
+
+    fn stream_part1(&self, reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        Ok(day3::stream::total_priority_sum(reader)?.to_string())
+    }
+
+    fn stream_part2(&self, reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        Ok(day3::stream::get_badges_sum(reader)?.to_string())
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        day3::gen::generate(seed, size)
+    }
+}
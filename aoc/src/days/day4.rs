@@ -0,0 +1,41 @@
+use std::io::BufRead;
+
+use aoc_common::InputSource;
+
+use crate::solution::Solution;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn default_input(&self) -> InputSource {
+        day4::INPUT
+    }
+
+    fn demo_input(&self) -> InputSource {
+        day4::DEMO
+    }
+
+    fn part1(&self, input: &InputSource) -> aoc_common::Result<String> {
+        Ok(day4::get_containing_pairs(input)?.to_string())
+    }
+
+    fn part2(&self, input: &InputSource) -> aoc_common::Result<String> {
+        Ok(day4::get_overlap_pairs(input)?.to_string())
+    }
+
+    fn stream_part1(&self, reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        Ok(day4::stream::get_containing_pairs(reader)?.to_string())
+    }
+
+    fn stream_part2(&self, reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        Ok(day4::stream::get_overlap_pairs(reader)?.to_string())
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        day4::gen::generate(seed, size)
+    }
+}
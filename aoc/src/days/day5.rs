@@ -0,0 +1,46 @@
+use aoc_common::InputSource;
+
+use crate::solution::Solution;
+use day5::item::Move;
+use day5::stack::Stacks;
+
+pub struct Day5;
+
+fn top_crates(
+    input: &InputSource,
+    do_move: fn(&mut Stacks, &Move) -> aoc_common::Result<()>,
+) -> aoc_common::Result<String> {
+    let (setup_str, moves) = day5::read_input(input)?;
+    let mut stacks = Stacks::from_arrangement(setup_str)?;
+
+    for todo in moves.iter() {
+        do_move(&mut stacks, todo)?;
+    }
+    Ok(stacks.top_str())
+}
+
+impl Solution for Day5 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn default_input(&self) -> InputSource {
+        day5::INPUT
+    }
+
+    fn demo_input(&self) -> InputSource {
+        day5::DEMO
+    }
+
+    fn part1(&self, input: &InputSource) -> aoc_common::Result<String> {
+        top_crates(input, Stacks::do_move_single_crate)
+    }
+
+    fn part2(&self, input: &InputSource) -> aoc_common::Result<String> {
+        top_crates(input, Stacks::do_move_multiple_crates)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        day5::gen::generate(seed, size)
+    }
+}
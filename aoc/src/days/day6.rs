@@ -0,0 +1,48 @@
+use std::io::BufRead;
+
+use aoc_common::InputSource;
+
+use crate::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day6;
+
+// one marker per datastream, the puzzle input only has the one
+fn markers(input: &InputSource, size: usize) -> aoc_common::Result<String> {
+    let markers = day6::markers(input, size)?;
+    Ok(markers.iter().join(","))
+}
+
+impl Solution for Day6 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn default_input(&self) -> InputSource {
+        day6::INPUT
+    }
+
+    fn demo_input(&self) -> InputSource {
+        day6::DEMO
+    }
+
+    fn part1(&self, input: &InputSource) -> aoc_common::Result<String> {
+        markers(input, 4)
+    }
+
+    fn part2(&self, input: &InputSource) -> aoc_common::Result<String> {
+        markers(input, 14)
+    }
+
+    fn stream_part1(&self, reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        Ok(day6::stream::markers(reader, 4)?.iter().join(","))
+    }
+
+    fn stream_part2(&self, reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        Ok(day6::stream::markers(reader, 14)?.iter().join(","))
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        day6::gen::generate(seed, size)
+    }
+}
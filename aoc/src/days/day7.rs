@@ -0,0 +1,41 @@
+use std::io::BufRead;
+
+use aoc_common::InputSource;
+
+use crate::solution::Solution;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn default_input(&self) -> InputSource {
+        day7::INPUT
+    }
+
+    fn demo_input(&self) -> InputSource {
+        day7::DEMO
+    }
+
+    fn part1(&self, input: &InputSource) -> aoc_common::Result<String> {
+        Ok(day7::small_dirs_total(input)?.to_string())
+    }
+
+    fn part2(&self, input: &InputSource) -> aoc_common::Result<String> {
+        Ok(day7::dir_to_delete_size(input)?.to_string())
+    }
+
+    fn stream_part1(&self, reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        Ok(day7::stream::small_dirs_total(reader)?.to_string())
+    }
+
+    fn stream_part2(&self, reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        Ok(day7::stream::dir_to_delete_size(reader)?.to_string())
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        day7::gen::generate(seed, size)
+    }
+}
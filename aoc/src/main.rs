@@ -0,0 +1,130 @@
+mod answers;
+mod days;
+mod report;
+mod solution;
+mod verify;
+
+use std::path::PathBuf;
+
+use aoc_common::InputSource;
+use clap::{Parser, Subcommand};
+use color_eyre::eyre::{bail, eyre};
+
+use answers::Answers;
+use report::Format;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day's puzzle, both parts unless one is given
+    Run {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// input file, `-` for stdin [default: $AOC_INPUT_DIR/dayN.txt if set,
+        /// otherwise the input bundled with the day crate]
+        #[arg(long, value_name = "FILE")]
+        input: Option<InputSource>,
+        /// read the input line by line instead of loading it up front, for
+        /// inputs too big for memory (all days but 5)
+        #[arg(long)]
+        stream: bool,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Print a seeded synthetic input, e.g. `aoc gen 3 | aoc run 3 --input -`
+    Gen {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// how much to generate: elves, rounds, groups of rucksacks, pairs,
+        /// moves, chars or files and directories, depending on the day
+        #[arg(long, default_value_t = 1000)]
+        size: usize,
+    },
+    /// Check the solvers against the expected answers
+    Verify {
+        /// only check this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// answers file [default: the answers.toml bundled with aoc]
+        #[arg(long, value_name = "FILE")]
+        answers: Option<PathBuf>,
+    },
+}
+
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    match Cli::parse().command {
+        Command::Run {
+            day,
+            part,
+            input,
+            stream,
+            format,
+        } => {
+            let solution = days::get(day).ok_or_else(|| eyre!("day {day} is not solved yet"))?;
+            let mut input = input
+                .or_else(|| InputSource::from_input_dir(day))
+                .unwrap_or_else(|| solution.default_input());
+
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            if stream && input == InputSource::Stdin && parts.len() > 1 {
+                bail!("stdin can only be streamed once, pick a part");
+            }
+            if !stream {
+                input = input.load()?;
+            }
+
+            let mut reports = Vec::new();
+            for part in parts {
+                let report = report::solve(solution, part, &input, stream)?;
+                if let Some(line) = report.line(format) {
+                    println!("{line}");
+                }
+                reports.push(report);
+            }
+            if let Some(summary) = report::summary(&reports, format) {
+                println!("{summary}");
+            }
+        }
+        Command::Gen { day, seed, size } => {
+            let solution = days::get(day).ok_or_else(|| eyre!("day {day} is not solved yet"))?;
+            print!("{}", solution.generate(seed, size));
+        }
+        Command::Verify { day, answers } => {
+            let answers = match answers {
+                Some(path) => Answers::load(&path)?,
+                None => Answers::bundled(),
+            };
+            let solutions: Vec<_> = days::ALL
+                .iter()
+                .copied()
+                .filter(|solution| day.is_none_or(|day| solution.day() == day))
+                .collect();
+
+            let checks = verify::verify(&answers, &solutions);
+            print!("{}", verify::table(&checks));
+
+            let failed = checks.iter().filter(|check| !check.passed()).count();
+            if failed > 0 {
+                bail!("{failed} of {} answers don't match", checks.len());
+            }
+            println!("all {} answers match", checks.len());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,216 @@
+use std::io::{self, BufRead, Read};
+use std::time::{Duration, Instant};
+
+use aoc_common::{Error, InputSource};
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+use sha2::{Digest, Sha256};
+
+use crate::solution::Solution;
+
+/// How `aoc run` prints its results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// one human readable line per part
+    Text,
+    /// a single JSON array once every part is solved
+    Json,
+    /// one JSON object per line, as soon as each part is solved
+    Ndjson,
+}
+
+/// One solved part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    #[serde(rename = "elapsed_ns", serialize_with = "nanos")]
+    pub elapsed: Duration,
+    /// hex SHA-256 of the input, to tell runs on different inputs apart
+    pub input_sha256: String,
+}
+
+fn nanos<S: Serializer>(elapsed: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(elapsed.as_nanos())
+}
+
+impl Report {
+    /// The report as printed right after solving, nothing for `Format::Json`
+    /// which waits for all of them
+    pub fn line(&self, format: Format) -> Option<String> {
+        match format {
+            Format::Text => Some(format!(
+                "Day {}, part {}: {} ({:?})",
+                self.day, self.part, self.answer, self.elapsed
+            )),
+            Format::Json => None,
+            Format::Ndjson => Some(serde_json::to_string(self).expect("reports serialize")),
+        }
+    }
+}
+
+/// Solve one part and time it.
+///
+/// Streamed input is hashed as the solver reads it, which is part of the
+/// time taken; loaded input is hashed before the clock starts.
+pub fn solve(
+    solution: &dyn Solution,
+    part: u8,
+    input: &InputSource,
+    stream: bool,
+) -> aoc_common::Result<Report> {
+    let (answer, elapsed, input_sha256) = if stream {
+        let mut reader = HashReader::new(input.reader()?);
+        let start = Instant::now();
+        let answer = solution.solve_stream(part, &mut reader)?;
+        (answer, start.elapsed(), reader.hash())
+    } else {
+        let hash = input_hash(input)?;
+        let start = Instant::now();
+        let answer = solution.solve(part, input)?;
+        (answer, start.elapsed(), hash)
+    };
+
+    Ok(Report {
+        day: solution.day(),
+        part,
+        answer,
+        elapsed,
+        input_sha256,
+    })
+}
+
+/// Everything left to print once all parts are solved
+pub fn summary(reports: &[Report], format: Format) -> Option<String> {
+    match format {
+        Format::Json => Some(serde_json::to_string_pretty(reports).expect("reports serialize")),
+        Format::Text | Format::Ndjson => None,
+    }
+}
+
+/// Hex SHA-256 of the whole input
+pub fn input_hash(input: &InputSource) -> aoc_common::Result<String> {
+    let mut reader = HashReader::new(input.reader()?);
+    io::copy(&mut reader, &mut io::sink()).map_err(|source| Error::Io {
+        input: input.to_string(),
+        source,
+    })?;
+    Ok(reader.hash())
+}
+
+/// Hashes everything read through it, so streamed input is hashed without a
+/// second pass
+pub struct HashReader<R> {
+    inner: R,
+    hasher: Sha256,
+}
+
+impl<R: BufRead> HashReader<R> {
+    pub fn new(inner: R) -> HashReader<R> {
+        HashReader {
+            inner,
+            hasher: Sha256::new(),
+        }
+    }
+
+    /// hex SHA-256 of what has been read so far
+    pub fn hash(self) -> String {
+        format!("{:x}", self.hasher.finalize())
+    }
+}
+
+impl<R: BufRead> Read for HashReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+impl<R: BufRead> BufRead for HashReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        // the buffer is still filled from the `fill_buf` that came before, so
+        // this doesn't read anything
+        if let Ok(buf) = self.inner.fill_buf() {
+            self.hasher.update(&buf[..amt.min(buf.len())]);
+        }
+        self.inner.consume(amt);
+    }
+}
+
+#[cfg(test)]
+mod test_report {
+    use super::*;
+
+    // sha256 of "abc"
+    const ABC: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+    fn report() -> Report {
+        Report {
+            day: 1,
+            part: 2,
+            answer: "45000".to_string(),
+            elapsed: Duration::from_micros(1500),
+            input_sha256: ABC.to_string(),
+        }
+    }
+
+    #[test]
+    fn hash_ok() {
+        assert_eq!(ABC, input_hash(&InputSource::embedded("abc")).unwrap());
+
+        // reading line by line hashes the same bytes
+        let mut reader = HashReader::new("abc".as_bytes());
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        assert_eq!(ABC, reader.hash());
+    }
+
+    #[test]
+    fn ndjson_ok() {
+        let line = report().line(Format::Ndjson).unwrap();
+        assert_eq!(
+            format!(
+                r#"{{"day":1,"part":2,"answer":"45000","elapsed_ns":1500000,"input_sha256":"{ABC}"}}"#
+            ),
+            line
+        );
+    }
+
+    #[test]
+    fn json_waits_for_summary() {
+        let reports = vec![report(), report()];
+        assert_eq!(None, reports[0].line(Format::Json));
+
+        let json = summary(&reports, Format::Json).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(2, parsed.as_array().unwrap().len());
+        assert_eq!(None, summary(&reports, Format::Ndjson));
+    }
+
+    #[test]
+    fn solve_ok() {
+        let day1 = crate::days::get(1).unwrap();
+        let loaded = solve(day1, 1, &day1.demo_input(), false).unwrap();
+        let streamed = solve(day1, 1, &day1.demo_input(), true).unwrap();
+
+        assert_eq!("24000", loaded.answer);
+        assert_eq!(
+            (loaded.answer, loaded.input_sha256),
+            (streamed.answer, streamed.input_sha256)
+        );
+    }
+
+    #[test]
+    fn text_ok() {
+        assert_eq!(
+            "Day 1, part 2: 45000 (1.5ms)",
+            report().line(Format::Text).unwrap()
+        );
+    }
+}
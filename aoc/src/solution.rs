@@ -0,0 +1,58 @@
+use std::io::BufRead;
+
+use aoc_common::{Error, InputSource};
+
+/// A day's puzzle, wired up to the solver in that day's crate.
+///
+/// Both parts return the answer as it should be typed into the puzzle page.
+pub trait Solution {
+    fn day(&self) -> u8;
+
+    /// the puzzle input that ships with the day crate
+    fn default_input(&self) -> InputSource;
+
+    /// the worked example from the puzzle text
+    fn demo_input(&self) -> InputSource;
+
+    fn part1(&self, input: &InputSource) -> aoc_common::Result<String>;
+
+    fn part2(&self, input: &InputSource) -> aoc_common::Result<String>;
+
+    /// a synthetic input, the same for the same seed and size
+    fn generate(&self, seed: u64, size: usize) -> String;
+
+    /// part 1 reading the input line by line, for inputs too big to load.
+    ///
+    /// Days that can't do that keep the default, which is an error.
+    fn stream_part1(&self, _reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        Err(cant_stream(self.day()))
+    }
+
+    fn stream_part2(&self, _reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        Err(cant_stream(self.day()))
+    }
+
+    fn solve(&self, part: u8, input: &InputSource) -> aoc_common::Result<String> {
+        match part {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            _ => Err(no_part(self.day(), part)),
+        }
+    }
+
+    fn solve_stream(&self, part: u8, reader: &mut dyn BufRead) -> aoc_common::Result<String> {
+        match part {
+            1 => self.stream_part1(reader),
+            2 => self.stream_part2(reader),
+            _ => Err(no_part(self.day(), part)),
+        }
+    }
+}
+
+fn no_part(day: u8, part: u8) -> Error {
+    Error::invalid(format!("day {day} has no part {part}"))
+}
+
+fn cant_stream(day: u8) -> Error {
+    Error::invalid(format!("day {day} can't stream its input"))
+}
@@ -0,0 +1,137 @@
+use std::fmt::Write;
+
+use crate::answers::{Answers, InputKind};
+use crate::solution::Solution;
+
+/// One expected answer, and what the solver actually gave
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub kind: InputKind,
+    pub part: u8,
+    pub expected: String,
+    pub actual: aoc_common::Result<String>,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        matches!(&self.actual, Ok(actual) if *actual == self.expected)
+    }
+
+    fn status(&self) -> &'static str {
+        match &self.actual {
+            Ok(_) if self.passed() => "ok",
+            Ok(_) => "MISMATCH",
+            Err(_) => "ERROR",
+        }
+    }
+}
+
+/// Run every solver part that has an expected answer
+pub fn verify(answers: &Answers, solutions: &[&dyn Solution]) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    for solution in solutions {
+        let day = solution.day();
+        for kind in InputKind::ALL {
+            let input = kind.source(*solution);
+            for part in 1..=2 {
+                let Some(expected) = answers.expected(day, kind, part) else {
+                    continue;
+                };
+                checks.push(Check {
+                    day,
+                    kind,
+                    part,
+                    expected: expected.to_string(),
+                    actual: solution.solve(part, &input),
+                });
+            }
+        }
+    }
+    checks
+}
+
+/// Results as an aligned plain-text table
+pub fn table(checks: &[Check]) -> String {
+    let header = ["day", "input", "part", "expected", "actual", "status"];
+    let rows: Vec<[String; 6]> = checks
+        .iter()
+        .map(|check| {
+            let actual = match &check.actual {
+                Ok(actual) => actual.clone(),
+                Err(e) => e.to_string(),
+            };
+            [
+                check.day.to_string(),
+                check.kind.to_string(),
+                check.part.to_string(),
+                check.expected.clone(),
+                actual,
+                check.status().to_string(),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut table = String::new();
+    let header = header.map(str::to_string);
+    for row in std::iter::once(&header).chain(&rows) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(table, "{}", line.trim_end()).unwrap();
+    }
+    table
+}
+
+#[cfg(test)]
+mod test_verify {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn bundled_answers_match() {
+        let checks = verify(&Answers::bundled(), days::ALL);
+        assert_eq!(days::ALL.len() * 4, checks.len());
+        assert!(checks.iter().all(Check::passed), "\n{}", table(&checks));
+    }
+
+    #[test]
+    fn streamed_answers_match() {
+        let answers = Answers::bundled();
+        for solution in days::ALL.iter().filter(|solution| solution.day() != 5) {
+            for kind in InputKind::ALL {
+                let input = kind.source(*solution);
+                for part in 1..=2 {
+                    let actual = solution.solve_stream(part, &mut input.reader().unwrap());
+                    let expected = answers.expected(solution.day(), kind, part);
+                    assert_eq!(expected, actual.ok().as_deref(), "day {}", solution.day());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reports_mismatch() {
+        let answers = Answers::parse("[day4.demo]\npart1 = \"3\"").unwrap();
+        let checks = verify(&answers, days::ALL);
+
+        assert_eq!(1, checks.len());
+        assert!(!checks[0].passed());
+        assert_eq!(
+            "day  input  part  expected  actual  status\n\
+             4    demo   1     3         2       MISMATCH\n",
+            table(&checks)
+        );
+    }
+}
@@ -0,0 +1,54 @@
+// cargo bench -p day1, report in target/criterion/report/index.html
+use std::cmp::Reverse;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use itertools::Itertools;
+
+use day1::another_solution::{self, GroupSumIter};
+use day1::{gen, max_calories, parallel, top_3_calories, INPUT};
+
+fn max(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day1/max");
+    group.bench_function("read_calories", |b| {
+        b.iter(|| max_calories(black_box(&INPUT)).unwrap())
+    });
+    group.bench_function("GroupSumIter", |b| {
+        b.iter(|| another_solution::total_calories(black_box(&INPUT)).unwrap())
+    });
+    group.finish();
+}
+
+fn top_3(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day1/top_3");
+    group.bench_function("read_calories", |b| {
+        b.iter(|| top_3_calories(black_box(&INPUT)).unwrap())
+    });
+    group.bench_function("k_smallest", |b| {
+        b.iter(|| another_solution::top_3(black_box(&INPUT)).unwrap())
+    });
+    group.finish();
+}
+
+// a couple of million elves, about 100MB
+fn large(c: &mut Criterion) {
+    let input = gen::generate(0, 2_000_000);
+    let mut group = c.benchmark_group("day1/large_top_3");
+    group.sample_size(10);
+    group.bench_function("GroupSumIter", |b| {
+        b.iter(|| {
+            let lines = black_box(&input).lines().map(|v| v.parse::<u64>().ok());
+            itertools::process_results(GroupSumIter::new(lines), |sums| {
+                sums.map(Reverse).k_smallest(3).map(|c| c.0).sum::<u64>()
+            })
+            .unwrap()
+        })
+    });
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    group.bench_function(format!("parallel/{threads}"), |b| {
+        b.iter(|| parallel::top_k_calories_in(black_box(&input), 3, threads).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, max, top_3, large);
+criterion_main!(benches);
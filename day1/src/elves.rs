@@ -0,0 +1,180 @@
+//! Which elves carry the calories, not just how many there are.
+
+use aoc_common::{InputSource, Result};
+
+use crate::read_calories_as;
+
+/// An elf and the calories it carries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    /// position of the elf's group in the input, from 0
+    pub index: usize,
+    pub calories: u64,
+}
+
+/// Every elf's calories total
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Elves {
+    // in input order
+    elves: Vec<Elf>,
+    // most calories first, ties in input order
+    ranked: Vec<Elf>,
+}
+
+impl FromIterator<u64> for Elves {
+    fn from_iter<T: IntoIterator<Item = u64>>(calories: T) -> Self {
+        let elves: Vec<Elf> = calories
+            .into_iter()
+            .enumerate()
+            .map(|(index, calories)| Elf { index, calories })
+            .collect();
+
+        let mut ranked = elves.clone();
+        ranked.sort_by_key(|elf| (std::cmp::Reverse(elf.calories), elf.index));
+        Elves { elves, ranked }
+    }
+}
+
+impl Elves {
+    /// Sum up the groups in the input, blank lines between them
+    pub fn read(input: &InputSource) -> Result<Elves> {
+        Ok(read_calories_as::<u64>(input)?.into_iter().collect())
+    }
+
+    /// in input order
+    pub fn all(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    pub fn len(&self) -> usize {
+        self.elves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elves.is_empty()
+    }
+
+    /// The `k` elves carrying the most, most first.
+    ///
+    /// Elves tied with the last one are included too, so there can be more
+    /// than `k` of them.
+    pub fn top_k(&self, k: usize) -> &[Elf] {
+        if k == 0 || self.ranked.is_empty() {
+            return &[];
+        }
+        let last = self.ranked[k.min(self.len()) - 1].calories;
+        let end = self
+            .ranked
+            .iter()
+            .position(|elf| elf.calories < last)
+            .unwrap_or(self.len());
+        &self.ranked[..end]
+    }
+
+    /// the elf carrying the most, the first one on a tie
+    pub fn leader(&self) -> Option<Elf> {
+        self.ranked.first().copied()
+    }
+
+    /// the elf carrying the least, the first one on a tie
+    pub fn min(&self) -> Option<Elf> {
+        self.elves.iter().min_by_key(|elf| elf.calories).copied()
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        // a u64 per elf can't add up past a u128
+        let total: u128 = self.elves.iter().map(|elf| u128::from(elf.calories)).sum();
+        (!self.is_empty()).then(|| total as f64 / self.len() as f64)
+    }
+
+    /// the middle total, or the mean of the two middle ones
+    pub fn median(&self) -> Option<f64> {
+        let n = self.len();
+        let middle = |i: usize| self.ranked[n - 1 - i].calories as f64;
+        match n {
+            0 => None,
+            _ if n % 2 == 1 => Some(middle(n / 2)),
+            _ => Some((middle(n / 2 - 1) + middle(n / 2)) / 2.0),
+        }
+    }
+
+    /// Nearest-rank percentile: the elf at least `p` percent of the elves
+    /// carry no more than. `p` goes from 0 to 100.
+    pub fn percentile(&self, p: f64) -> Option<Elf> {
+        if self.is_empty() || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        let n = self.len();
+        let rank = ((p / 100.0 * n as f64).ceil() as usize).clamp(1, n);
+        // `ranked` goes from most to least
+        Some(self.ranked[n - rank])
+    }
+}
+
+#[cfg(test)]
+mod test_elves {
+    use super::*;
+    use crate::{DEMO, INPUT};
+
+    fn elf(index: usize, calories: u64) -> Elf {
+        Elf { index, calories }
+    }
+
+    #[test]
+    fn read_ok() {
+        let elves = Elves::read(&DEMO).unwrap();
+        assert_eq!(
+            &[elf(0, 5000), elf(1, 4000), elf(2, 11000), elf(3, 24000)],
+            elves.all()
+        );
+
+        let elves = Elves::read(&INPUT).unwrap();
+        let top: u64 = elves.top_k(3).iter().map(|elf| elf.calories).sum();
+        assert_eq!(195625, top);
+    }
+
+    #[test]
+    fn top_k_ok() {
+        let elves = Elves::read(&DEMO).unwrap();
+        assert_eq!(&[elf(3, 24000)], elves.top_k(1));
+        assert_eq!(&[elf(3, 24000), elf(2, 11000)], elves.top_k(2));
+        assert_eq!(4, elves.top_k(10).len());
+        assert!(elves.top_k(0).is_empty());
+    }
+
+    #[test]
+    fn top_k_ties() {
+        let elves: Elves = [1, 2, 2, 1].into_iter().collect();
+        assert_eq!(&[elf(1, 2), elf(2, 2)], elves.top_k(1));
+        assert_eq!(4, elves.top_k(3).len());
+    }
+
+    #[test]
+    fn stats_ok() {
+        let elves = Elves::read(&DEMO).unwrap();
+        assert_eq!(Some(elf(3, 24000)), elves.leader());
+        assert_eq!(Some(elf(1, 4000)), elves.min());
+        assert_eq!(Some(11000.0), elves.mean());
+        assert_eq!(Some(8000.0), elves.median());
+
+        assert_eq!(Some(elf(1, 4000)), elves.percentile(0.0));
+        assert_eq!(Some(elf(0, 5000)), elves.percentile(50.0));
+        assert_eq!(Some(elf(3, 24000)), elves.percentile(100.0));
+        assert_eq!(None, elves.percentile(101.0));
+    }
+
+    #[test]
+    fn mean_of_big_totals() {
+        let elves = Elves::from_iter([u64::MAX, u64::MAX]);
+        assert_eq!(Some(u64::MAX as f64), elves.mean());
+    }
+
+    #[test]
+    fn stats_empty() {
+        let elves = Elves::default();
+        assert_eq!(None, elves.leader());
+        assert_eq!(None, elves.mean());
+        assert_eq!(None, elves.median());
+        assert_eq!(None, elves.percentile(50.0));
+    }
+}
@@ -0,0 +1,38 @@
+//! Seeded synthetic inputs, for stress testing the solvers.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// `size` elves, each carrying 1 to 15 items of 1000 to 60000 calories
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut input = String::new();
+
+    for elf in 0..size {
+        if elf > 0 {
+            input.push('\n');
+        }
+        for _ in 0..rng.gen_range(1..=15) {
+            let calories: u32 = rng.gen_range(1000..=60000);
+            input.push_str(&format!("{calories}\n"));
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod test_gen {
+    use super::*;
+    use crate::read_calories;
+    use aoc_common::InputSource;
+
+    #[test]
+    fn generate_ok() {
+        let input = generate(7, 500);
+        assert_eq!(input, generate(7, 500));
+        assert_ne!(input, generate(8, 500));
+
+        let calories = read_calories(&InputSource::text(input)).unwrap();
+        assert_eq!(500, calories.len());
+    }
+}
@@ -0,0 +1,299 @@
+//! Reduce runs of items split by separators, like the paragraphs of numbers
+//! in day 1's input.
+
+use std::fmt;
+
+use num_traits::{CheckedAdd, CheckedMul};
+
+/// How the items of a group are combined into a single value
+pub trait Reducer<T> {
+    type Output;
+
+    /// the value of a group holding just `item`, `None` if it doesn't fit
+    fn first(&mut self, item: T) -> Option<Self::Output>;
+
+    /// fold the next `item` into `acc`, `None` if it doesn't fit
+    fn next(&mut self, acc: Self::Output, item: T) -> Option<Self::Output>;
+}
+
+/// checked sum of the items
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sum;
+
+impl<T: CheckedAdd> Reducer<T> for Sum {
+    type Output = T;
+
+    fn first(&mut self, item: T) -> Option<T> {
+        Some(item)
+    }
+
+    fn next(&mut self, acc: T, item: T) -> Option<T> {
+        acc.checked_add(&item)
+    }
+}
+
+/// checked product of the items
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Product;
+
+impl<T: CheckedMul> Reducer<T> for Product {
+    type Output = T;
+
+    fn first(&mut self, item: T) -> Option<T> {
+        Some(item)
+    }
+
+    fn next(&mut self, acc: T, item: T) -> Option<T> {
+        acc.checked_mul(&item)
+    }
+}
+
+/// largest item
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Max;
+
+impl<T: Ord> Reducer<T> for Max {
+    type Output = T;
+
+    fn first(&mut self, item: T) -> Option<T> {
+        Some(item)
+    }
+
+    fn next(&mut self, acc: T, item: T) -> Option<T> {
+        Some(acc.max(item))
+    }
+}
+
+/// Any fold starting from `init`, `f` returns `None` when it overflows
+#[derive(Debug, Clone)]
+pub struct Fold<A, F> {
+    init: A,
+    f: F,
+}
+
+impl<A, F> Fold<A, F> {
+    pub fn new(init: A, f: F) -> Fold<A, F> {
+        Fold { init, f }
+    }
+}
+
+impl<T, A: Clone, F: FnMut(A, T) -> Option<A>> Reducer<T> for Fold<A, F> {
+    type Output = A;
+
+    fn first(&mut self, item: T) -> Option<A> {
+        (self.f)(self.init.clone(), item)
+    }
+
+    fn next(&mut self, acc: A, item: T) -> Option<A> {
+        (self.f)(acc, item)
+    }
+}
+
+/// For `Option` items split at `None`: reduces what's in the `Some`s with `R`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Somes<R>(pub R);
+
+impl<T, R: Reducer<T>> Reducer<Option<T>> for Somes<R> {
+    type Output = R::Output;
+
+    // separators never get here, so `?` only drops a `None` nobody split at
+    fn first(&mut self, item: Option<T>) -> Option<R::Output> {
+        self.0.first(item?)
+    }
+
+    fn next(&mut self, acc: R::Output, item: Option<T>) -> Option<R::Output> {
+        match item {
+            Some(item) => self.0.next(acc, item),
+            None => Some(acc),
+        }
+    }
+}
+
+/// A group whose value doesn't fit in the reducer's output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    /// which group, from 0
+    pub group: usize,
+    /// position of the item that overflowed in the whole input, from 0
+    pub item: usize,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "group {} overflows at item {}", self.group, self.item)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+impl From<Overflow> for aoc_common::Error {
+    fn from(overflow: Overflow) -> Self {
+        aoc_common::Error::invalid(overflow.to_string())
+    }
+}
+
+/// Reduced value of each group of items, items for which `is_separator`
+/// holds separating the groups.
+///
+/// Runs of separators don't make empty groups. A group that overflows is
+/// reported once, then the next group carries on.
+pub struct Groups<I, P, R> {
+    inner: I,
+    is_separator: P,
+    reducer: R,
+    group: usize,
+    item: usize,
+}
+
+impl<I, P, R> Groups<I, P, R> {
+    pub fn new(inner: I, is_separator: P, reducer: R) -> Groups<I, P, R> {
+        Groups {
+            inner,
+            is_separator,
+            reducer,
+            group: 0,
+            item: 0,
+        }
+    }
+}
+
+impl<I, P, R> Groups<I, P, R>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    // next item of the current group, `None` at a separator or the end
+    fn next_item(&mut self) -> Option<I::Item> {
+        let item = self.inner.next()?;
+        self.item += 1;
+        (!(self.is_separator)(&item)).then_some(item)
+    }
+
+    fn overflow(&mut self) -> Overflow {
+        let overflow = Overflow {
+            group: self.group,
+            item: self.item - 1,
+        };
+        // skip the rest of the group
+        while self.next_item().is_some() {}
+        overflow
+    }
+}
+
+impl<I, P, R> Iterator for Groups<I, P, R>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+    R: Reducer<I::Item>,
+{
+    type Item = Result<R::Output, Overflow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = loop {
+            let item = self.inner.next()?;
+            self.item += 1;
+            if !(self.is_separator)(&item) {
+                break item;
+            }
+        };
+
+        let Some(mut acc) = self.reducer.first(first) else {
+            let overflow = self.overflow();
+            self.group += 1;
+            return Some(Err(overflow));
+        };
+        while let Some(item) = self.next_item() {
+            match self.reducer.next(acc, item) {
+                Some(next) => acc = next,
+                None => {
+                    let overflow = self.overflow();
+                    self.group += 1;
+                    return Some(Err(overflow));
+                }
+            }
+        }
+        self.group += 1;
+        Some(Ok(acc))
+    }
+}
+
+/// `.groups(..)` on any iterator
+pub trait GroupsExt: Iterator + Sized {
+    fn groups<P, R>(self, is_separator: P, reducer: R) -> Groups<Self, P, R>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        R: Reducer<Self::Item>,
+    {
+        Groups::new(self, is_separator, reducer)
+    }
+}
+
+impl<I: Iterator> GroupsExt for I {}
+
+#[cfg(test)]
+mod test_groups {
+    use super::*;
+
+    fn demo() -> Vec<Option<u32>> {
+        include_str!("../demo.txt")
+            .lines()
+            .map(|line| line.parse().ok())
+            .collect()
+    }
+
+    #[test]
+    fn sum_ok() {
+        let sums: Result<Vec<u32>, _> = demo()
+            .into_iter()
+            .groups(Option::is_none, Somes(Sum))
+            .collect();
+        assert_eq!(vec![5000, 4000, 11000, 24000], sums.unwrap());
+    }
+
+    #[test]
+    fn product_and_max_ok() {
+        let items = [2u8, 3, 0, 7, 5, 0, 0, 4];
+        let is_zero = |x: &u8| *x == 0;
+
+        let products: Vec<_> = items.iter().copied().groups(is_zero, Product).collect();
+        assert_eq!(vec![Ok(6), Ok(35), Ok(4)], products);
+
+        let maxes: Vec<_> = items.iter().copied().groups(is_zero, Max).collect();
+        assert_eq!(vec![Ok(3), Ok(7), Ok(4)], maxes);
+    }
+
+    #[test]
+    fn fold_ok() {
+        // number of items in each paragraph
+        let lines = ["a", "b", "", "c", "", "", "d", "e", "f"];
+        let counts: Vec<_> = lines
+            .into_iter()
+            .groups(
+                |line| line.is_empty(),
+                Fold::new(0u8, |n: u8, _| n.checked_add(1)),
+            )
+            .collect();
+        assert_eq!(vec![Ok(2), Ok(1), Ok(3)], counts);
+    }
+
+    #[test]
+    fn overflow_reported() {
+        let items = [
+            Some(1u8),
+            None,
+            Some(200),
+            Some(100),
+            Some(1),
+            None,
+            Some(3),
+        ];
+        let sums: Vec<_> = items
+            .into_iter()
+            .groups(Option::is_none, Somes(Sum))
+            .collect();
+        assert_eq!(
+            vec![Ok(1), Err(Overflow { group: 1, item: 3 }), Ok(3)],
+            sums
+        );
+    }
+}
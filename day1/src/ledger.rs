@@ -0,0 +1,174 @@
+//! Calories logged as they come in, with the leaders known at any point.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BTreeSet;
+
+use aoc_common::{InputLine, Result};
+
+use crate::elves::Elf;
+use crate::utlis::ElfTotal;
+use crate::DAY;
+
+// set order: the greatest has the most calories, then came first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Ranked(Elf);
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.0.calories, Reverse(self.0.index)).cmp(&(other.0.calories, Reverse(other.0.index)))
+    }
+}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Running tally of the elves' calories, fed one line at a time.
+///
+/// Only finished elves are ranked, the one being logged shows in
+/// [`CalorieLedger::current`]. Adding an elf and finding the leader are
+/// O(log n), the top k O(log n + k).
+pub struct CalorieLedger {
+    // lines pushed so far, for errors
+    lines: usize,
+    total: ElfTotal<u64>,
+    ranked: BTreeSet<Ranked>,
+}
+
+impl Default for CalorieLedger {
+    fn default() -> Self {
+        CalorieLedger::new()
+    }
+}
+
+impl CalorieLedger {
+    pub fn new() -> CalorieLedger {
+        CalorieLedger {
+            lines: 0,
+            total: ElfTotal::new(),
+            ranked: BTreeSet::new(),
+        }
+    }
+
+    /// Log a line of input: calories for the current elf, or a blank line
+    /// to finish it.
+    ///
+    /// A line that doesn't parse or overflows is an error and leaves the
+    /// ledger as it was.
+    pub fn push_line(&mut self, text: &str) -> Result<()> {
+        self.lines += 1;
+        let line = InputLine::new(DAY, self.lines, text);
+        if let Some(calories) = self.total.push(line)? {
+            self.rank(calories);
+        }
+        Ok(())
+    }
+
+    /// finish the current elf, like a blank line does
+    pub fn end_group(&mut self) {
+        if let Some(calories) = self.total.end() {
+            self.rank(calories);
+        }
+    }
+
+    fn rank(&mut self, calories: u64) {
+        let index = self.ranked.len();
+        self.ranked.insert(Ranked(Elf { index, calories }));
+    }
+
+    /// calories of the elf being logged
+    pub fn current(&self) -> Option<u64> {
+        self.total.current().copied()
+    }
+
+    /// finished elves
+    pub fn len(&self) -> usize {
+        self.ranked.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranked.is_empty()
+    }
+
+    /// the elf carrying the most, the first one on a tie
+    pub fn leader(&self) -> Option<Elf> {
+        self.ranked.last().map(|ranked| ranked.0)
+    }
+
+    /// The `k` elves carrying the most, most first, ties in the order they
+    /// were logged.
+    pub fn top_k(&self, k: usize) -> Vec<Elf> {
+        self.ranked
+            .iter()
+            .rev()
+            .take(k)
+            .map(|ranked| ranked.0)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test_ledger {
+    use super::*;
+    use crate::elves::Elves;
+    use crate::{DEMO, INPUT};
+
+    fn ledger(input: &str) -> CalorieLedger {
+        let mut ledger = CalorieLedger::new();
+        for line in input.lines() {
+            ledger.push_line(line).unwrap();
+        }
+        ledger
+    }
+
+    #[test]
+    fn ledger_ok() {
+        let mut ledger = ledger(&DEMO.read().unwrap());
+        // the last elf isn't followed by a blank line
+        assert_eq!(3, ledger.len());
+        assert_eq!(Some(24000), ledger.current());
+        assert_eq!(11000, ledger.leader().unwrap().calories);
+
+        ledger.end_group();
+        assert_eq!(None, ledger.current());
+        assert_eq!(
+            Some(Elf {
+                index: 3,
+                calories: 24000
+            }),
+            ledger.leader()
+        );
+
+        let top: Vec<u64> = ledger.top_k(3).iter().map(|elf| elf.calories).collect();
+        assert_eq!(vec![24000, 11000, 5000], top);
+    }
+
+    #[test]
+    fn ledger_matches_elves() {
+        let input = INPUT.read().unwrap();
+        let mut ledger = ledger(&input);
+        ledger.end_group();
+        let elves = Elves::read(&INPUT).unwrap();
+        assert_eq!(elves.leader(), ledger.leader());
+        assert_eq!(elves.top_k(3), ledger.top_k(3));
+    }
+
+    #[test]
+    fn ledger_ties() {
+        let ledger = ledger("2\n\n3\n\n3\n\n");
+        assert_eq!(1, ledger.leader().unwrap().index);
+        let top: Vec<usize> = ledger.top_k(2).iter().map(|elf| elf.index).collect();
+        assert_eq!(vec![1, 2], top);
+    }
+
+    #[test]
+    fn bad_line_keeps_ledger() {
+        let mut ledger = ledger("100\n");
+        assert!(ledger.push_line("lots").is_err());
+        assert!(ledger.push_line(&u64::MAX.to_string()).is_err());
+        ledger.push_line("5").unwrap();
+        assert_eq!(Some(105), ledger.current());
+    }
+}
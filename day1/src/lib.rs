@@ -0,0 +1,14 @@
+pub mod elves;
+pub mod gen;
+pub mod groups;
+pub mod ledger;
+pub mod parallel;
+pub mod stream;
+mod utlis;
+pub use utlis::*;
+
+use aoc_common::InputSource;
+
+pub const DAY: u8 = 1;
+pub const INPUT: InputSource = InputSource::embedded(include_str!("input.txt"));
+pub const DEMO: InputSource = InputSource::embedded(include_str!("../demo.txt"));
@@ -3,24 +3,72 @@
 // Find the maximum sum
 // Find which elf is carrying the maximum sum
 
-mod utlis;
-use utlis::*;
-pub use std::fs;
-const INPUT: &str = "/home/user/dev/tutorial/adventOfCode/2022/day1/src/input.txt";
-
-fn main() {
-    // let mut total_calories = read_calories(INPUT);
-    // total_calories.sort();
-    // let l = total_calories.len();
-    // println!("1: {}, 2:{}, 3:{}", total_calories[l-1], total_calories[l-2], total_calories[l-3]);
-
-    // let mut top3 = 0;
-    // for i in 1..4{
-    //     top3 += total_calories[l-i];
-    // }
-    // println!("top3: {}", top3);
-
-    another_solution::top_3();
-    another_solution::total_calories();
+use std::io::{self, BufRead, Write};
 
+use day1::ledger::CalorieLedger;
+use day1::*;
+
+fn main() -> aoc_common::Result<()> {
+    if std::env::args().any(|arg| arg == "--repl") {
+        return repl();
+    }
+
+    println!("max: {}", max_calories(&INPUT)?);
+    println!("top3: {}", top_3_calories(&INPUT)?);
+
+    let elves = elves::Elves::read(&INPUT)?;
+    if let Some(leader) = elves.leader() {
+        println!("carried by elf #{}", leader.index + 1);
+    }
+    Ok(())
+}
+
+const HELP: &str = "\
+calories for the current elf, a blank line to finish it, or
+  leader   the elf carrying the most
+  top K    the K elves carrying the most
+  quit";
+
+// log calories from stdin, answering questions along the way
+fn repl() -> aoc_common::Result<()> {
+    let mut ledger = CalorieLedger::new();
+    println!("{HELP}");
+    prompt();
+
+    for line in io::stdin().lock().lines() {
+        let line = line.map_err(|source| aoc_common::Error::Io {
+            input: "stdin".into(),
+            source,
+        })?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        match words[..] {
+            ["quit"] => break,
+            ["leader"] => match ledger.leader() {
+                Some(elf) => println!("elf #{}: {}", elf.index + 1, elf.calories),
+                None => println!("no elf is done yet"),
+            },
+            ["top", k] => match k.parse() {
+                Ok(k) => {
+                    for elf in ledger.top_k(k) {
+                        println!("elf #{}: {}", elf.index + 1, elf.calories);
+                    }
+                }
+                Err(_) => println!("top what? {k:?} isn't a number"),
+            },
+            ["help"] => println!("{HELP}"),
+            _ => {
+                if let Err(err) = ledger.push_line(&line) {
+                    println!("{err}");
+                }
+            }
+        }
+        prompt();
+    }
+    Ok(())
+}
+
+fn prompt() {
+    print!("> ");
+    let _ = io::stdout().flush();
 }
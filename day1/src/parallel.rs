@@ -0,0 +1,184 @@
+//! Summing the elves' calories on several threads, for very big inputs.
+//!
+//! The input is cut at blank lines into a chunk per thread, so no elf is
+//! split. Each chunk keeps its own top `k`, and those get merged.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::thread;
+
+use aoc_common::{numbered_lines, InputSource, Result};
+
+use crate::utlis::ElfTotal;
+use crate::{read_calories_as, DAY};
+
+/// The `k` largest calorie totals, most first, on as many threads as there
+/// are cores
+pub fn top_k_calories(input: &InputSource, k: usize) -> Result<Vec<u64>> {
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    top_k_calories_in(&input.read()?, k, threads)
+}
+
+/// The `k` largest calorie totals, most first, on `threads` threads.
+///
+/// Errors are the same as [`read_calories_as`] gives, the input is read
+/// again in order to find where the first one is.
+pub fn top_k_calories_in(input: &str, k: usize, threads: usize) -> Result<Vec<u64>> {
+    let chunks = chunks(input, threads);
+
+    let partials: Vec<Option<TopK>> = thread::scope(|s| {
+        let handles: Vec<_> = chunks
+            .iter()
+            .map(|chunk| s.spawn(move || top_k_of(chunk, k)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("chunk thread panicked"))
+            .collect()
+    });
+
+    let Some(partials) = partials.into_iter().collect::<Option<Vec<_>>>() else {
+        // some chunk is bad, let the sequential reader say where
+        read_calories_as::<u64>(&InputSource::text(input))?;
+        unreachable!("the sequential reader accepted a chunk the parallel one didn't")
+    };
+
+    let mut merged = TopK::new(k);
+    for partial in partials {
+        merged.extend(partial);
+    }
+    Ok(merged.into_sorted())
+}
+
+// the `k` largest totals seen, smallest on top so it's the one to go
+struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<u64>>,
+}
+
+impl TopK {
+    fn new(k: usize) -> TopK {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    fn push(&mut self, calories: u64) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(calories));
+        } else if self.heap.peek().is_some_and(|min| min.0 < calories) {
+            self.heap.pop();
+            self.heap.push(Reverse(calories));
+        }
+    }
+
+    fn extend(&mut self, other: TopK) {
+        for calories in other.heap {
+            self.push(calories.0);
+        }
+    }
+
+    fn into_sorted(self) -> Vec<u64> {
+        // sorting `Reverse`s ascending puts the most calories first
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|c| c.0)
+            .collect()
+    }
+}
+
+// `None` if the chunk has a bad line
+fn top_k_of(chunk: &str, k: usize) -> Option<TopK> {
+    let mut top = TopK::new(k);
+    let mut total = ElfTotal::<u64>::new();
+
+    for line in numbered_lines(DAY, chunk) {
+        if let Some(calories) = total.push(line).ok()? {
+            top.push(calories);
+        }
+    }
+    if let Some(calories) = total.end() {
+        top.push(calories);
+    }
+    Some(top)
+}
+
+// about `n` chunks of the input, each ending after a blank line
+fn chunks(input: &str, n: usize) -> Vec<&str> {
+    let size = input.len() / n.max(1) + 1;
+    let mut chunks = Vec::with_capacity(n);
+    let mut rest = input;
+
+    while rest.len() > size {
+        let Some(cut) = after_blank_line(rest, size) else {
+            break;
+        };
+        let (chunk, tail) = rest.split_at(cut);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    chunks.push(rest);
+    chunks
+}
+
+// where the first blank line starting at or after `from` ends
+fn after_blank_line(input: &str, from: usize) -> Option<usize> {
+    let bytes = input.as_bytes();
+    let newline = |start: usize| {
+        bytes[start..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map(|i| start + i + 1)
+    };
+
+    // only cut at line starts, which are always char boundaries
+    let mut start = newline(from.saturating_sub(1))?;
+    loop {
+        let end = newline(start)?;
+        if input[start..end].trim().is_empty() {
+            return Some(end);
+        }
+        start = end;
+    }
+}
+
+#[cfg(test)]
+mod test_parallel {
+    use super::*;
+    use crate::{gen, top_3_calories, DEMO, INPUT};
+
+    #[test]
+    fn chunks_end_at_blank_lines() {
+        let input = gen::generate(5, 500);
+        let chunks = chunks(&input, 7);
+        assert!(chunks.len() > 1);
+        assert_eq!(input, chunks.concat());
+        for chunk in &chunks[..chunks.len() - 1] {
+            assert!(chunk.ends_with("\n\n"));
+        }
+    }
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let generated = InputSource::text(gen::generate(9, 3000));
+        for input in [DEMO, INPUT, generated] {
+            let expected = top_3_calories(&input).unwrap() as u64;
+            for threads in [1, 2, 5, 16] {
+                let top = top_k_calories_in(&input.read().unwrap(), 3, threads).unwrap();
+                assert_eq!(expected, top.iter().sum::<u64>());
+            }
+        }
+    }
+
+    #[test]
+    fn parallel_error_located() {
+        let mut input = gen::generate(2, 400);
+        input.push_str("\n12x\n");
+        let Err(aoc_common::Error::Parse(err)) = top_k_calories_in(&input, 3, 4) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(input.lines().count(), err.line);
+    }
+}
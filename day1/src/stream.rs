@@ -0,0 +1,75 @@
+//! Solvers reading the input line by line, in constant memory.
+
+use std::io::BufRead;
+
+use aoc_common::{for_each_line, Error, Result};
+
+use crate::utlis::ElfTotal;
+use crate::DAY;
+
+// calls `f` with the calories carried by each elf, in order
+fn for_each_elf(reader: impl BufRead, mut f: impl FnMut(u32)) -> Result<()> {
+    let mut total = ElfTotal::new();
+
+    for_each_line(DAY, reader, |line| {
+        if let Some(calories) = total.push(line)? {
+            f(calories);
+        }
+        Ok(())
+    })?;
+    if let Some(calories) = total.finish() {
+        f(calories);
+    }
+    Ok(())
+}
+
+pub fn max_calories(reader: impl BufRead) -> Result<u32> {
+    let mut max = 0;
+    for_each_elf(reader, |calories| max = max.max(calories))?;
+    Ok(max)
+}
+
+pub fn top_3_calories(reader: impl BufRead) -> Result<u32> {
+    // largest first
+    let mut top = [0; 3];
+    for_each_elf(reader, |calories| {
+        if let Some(pos) = top.iter().position(|&t| calories > t) {
+            top[pos..].rotate_right(1);
+            top[pos] = calories;
+        }
+    })?;
+    top.iter()
+        .try_fold(0u32, |sum, &c| sum.checked_add(c))
+        .ok_or_else(|| Error::invalid("the top 3 elves carry more calories than a u32 holds"))
+}
+
+#[cfg(test)]
+mod test_stream {
+    use super::*;
+    use crate::{gen, DEMO, INPUT};
+    use aoc_common::InputSource;
+
+    #[test]
+    fn stream_matches_read_calories() {
+        let generated = InputSource::text(gen::generate(3, 1000));
+        for input in [DEMO, INPUT, generated] {
+            let reader = input.reader().unwrap();
+            assert_eq!(
+                crate::max_calories(&input).unwrap(),
+                max_calories(reader).unwrap()
+            );
+            let reader = input.reader().unwrap();
+            assert_eq!(
+                crate::top_3_calories(&input).unwrap(),
+                top_3_calories(reader).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn top_3_overflow() {
+        let input = "2000000000\n\n2000000000\n\n2000000000\n";
+        assert!(top_3_calories(input.as_bytes()).is_err());
+        assert!(crate::top_3_calories(&InputSource::text(input)).is_err());
+    }
+}
@@ -1,15 +1,23 @@
-use std::fs;
-use std::path::Path;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc_common::{paragraphs, Error, InputLine, InputSource, Result};
+use num_traits::CheckedAdd;
+
+use crate::DAY;
 
 pub mod another_solution {
     // https://fasterthanli.me/series/advent-of-code-2022/part-1#the-problem-statement
 
-    use itertools::{FoldWhile, Itertools};
+    use aoc_common::InputSource;
+    use itertools::Itertools;
+    use num_traits::CheckedAdd;
     use std::cmp::Reverse;
-    pub fn top_3() -> color_eyre::Result<()> {
-        color_eyre::install()?;
 
-        let answer = include_str!("input.txt")
+    use crate::groups::{Groups, Overflow, Somes, Sum};
+    pub fn top_3(input: &InputSource) -> aoc_common::Result<u64> {
+        let answer = input
+            .read()?
             .lines()
             .map(|v| v.parse::<u64>().ok())
             .batching(|it| it.map_while(|x| x).sum1::<u64>())
@@ -17,97 +25,167 @@ pub mod another_solution {
             .k_smallest(3)
             .map(|x| x.0)
             .sum::<u64>();
-        println!("{answer:?}");
 
-        Ok(())
+        Ok(answer)
     }
 
-    pub fn total_calories() -> u64 {
-        let lines = include_str!("input.txt")
-            .lines()
-            .map(|v| v.parse::<u64>().ok());
+    pub fn total_calories(input: &InputSource) -> aoc_common::Result<u64> {
+        let input = input.read()?;
+        let lines = input.lines().map(|v| v.parse::<u64>().ok());
+
+        let elven_lead = itertools::process_results(GroupSumIter::new(lines), |sums| sums.max())?;
+        Ok(elven_lead.unwrap_or_default())
+    }
+
+    type IsNone<T> = fn(&Option<T>) -> bool;
 
-        let elven_lead = GroupSumIter { inner: lines }.max();
-        elven_lead.unwrap()
+    /// Sums of the groups of `Some` values, `None` separating the groups.
+    ///
+    /// A sum that doesn't fit in `T` is an [`Overflow`] error.
+    pub struct GroupSumIter<I, T = u64> {
+        groups: Groups<I, IsNone<T>, Somes<Sum>>,
     }
 
-    struct GroupSumIter<I> {
-        inner: I,
+    impl<I, T> GroupSumIter<I, T> {
+        pub fn new(inner: I) -> GroupSumIter<I, T> {
+            GroupSumIter {
+                groups: Groups::new(inner, Option::is_none, Somes(Sum)),
+            }
+        }
     }
 
-    impl<I> Iterator for GroupSumIter<I>
+    impl<I, T> Iterator for GroupSumIter<I, T>
     where
-        I: Iterator<Item = Option<u64>>,
+        I: Iterator<Item = Option<T>>,
+        T: CheckedAdd,
     {
-        type Item = u64;
+        type Item = Result<T, Overflow>;
 
         fn next(&mut self) -> Option<Self::Item> {
-            let mut sum = loop {
-                match self.inner.next() {
-                    Some(Some(v)) => break v,
-                    Some(None) => {
-                        // huh, weird, didn't expect a separator there
-                        // but let's just skip it
-                    }
-                    // we've reached the end of the inner iterator
-                    None => return None,
-                }
-            };
-
-            loop {
-                match self.inner.next() {
-                    Some(Some(v)) => sum += v,
-                    Some(None) | None => {
-                        // reached a separator or the end of the iterator
-                        break Some(sum);
-                    }
-                }
-            }
+            self.groups.next()
         }
     }
 }
 
-pub fn read_calories(input: &str) -> Vec<u32> {
-    let mut cals_str = read_lines(input);
-    cals_str.push("".to_string());
-
-    let mut calories: Vec<u32> = Vec::new();
-    let mut sum = 0;
-
-    // parse each calorie and sum those in a grp and push them to a vec
-    for item in cals_str.into_iter() {
-        if item == "" {
-            calories.push(sum);
-            sum = 0;
-        } else {
-            let calorie: u32 = item
-                .parse::<u32>()
-                .expect("No spacing after numbers and anything similar");
-            sum += calorie;
+/// What elves' calories are summed in: `u32`, `u64`, `u128` or a big
+/// integer like `num_bigint::BigUint`
+pub trait Calories: CheckedAdd {
+    fn parse_calories(item: &str) -> std::result::Result<Self, String>;
+}
+
+impl<T> Calories for T
+where
+    T: FromStr + CheckedAdd,
+    T::Err: Display,
+{
+    fn parse_calories(item: &str) -> std::result::Result<T, String> {
+        item.parse().map_err(|e: T::Err| e.to_string())
+    }
+}
+
+/// Running total of the elf being read, elves numbered from 1 in errors
+pub(crate) struct ElfTotal<T> {
+    elf: usize,
+    sum: Option<T>,
+}
+
+impl<T: Calories> ElfTotal<T> {
+    pub(crate) fn new() -> ElfTotal<T> {
+        ElfTotal { elf: 1, sum: None }
+    }
+
+    /// Add the line's calories, or at a blank line give back the finished total.
+    ///
+    /// On an error the total so far is kept.
+    pub(crate) fn push(&mut self, line: InputLine) -> Result<Option<T>> {
+        let item = line.text.trim();
+        if item.is_empty() {
+            return Ok(self.end());
         }
+
+        let elf = self.elf;
+        let calories = T::parse_calories(item)
+            .map_err(|e| line.error_at(item, format!("elf #{elf}: not a calorie count ({e})")))?;
+        let sum = match &self.sum {
+            None => calories,
+            Some(sum) => sum.checked_add(&calories).ok_or_else(|| {
+                let width = std::any::type_name::<T>();
+                line.error_at(
+                    item,
+                    format!("elf #{elf} carries more calories than a {width} holds"),
+                )
+            })?,
+        };
+        self.sum = Some(sum);
+        Ok(None)
     }
-    calories
+
+    /// the elf's total so far
+    pub(crate) fn current(&self) -> Option<&T> {
+        self.sum.as_ref()
+    }
+
+    /// done with this elf, its total if it carries anything
+    pub(crate) fn end(&mut self) -> Option<T> {
+        let total = self.sum.take();
+        self.elf += total.is_some() as usize;
+        total
+    }
+
+    /// the last elf's total, if the input doesn't end in a blank line
+    pub(crate) fn finish(self) -> Option<T> {
+        self.sum
+    }
+}
+
+/// Calories carried by each elf, summed in `T`.
+///
+/// A total that doesn't fit in `T` is an error naming the elf and the line.
+pub fn read_calories_as<T: Calories>(input: &InputSource) -> Result<Vec<T>> {
+    let input = input.read()?;
+    let mut calories = Vec::new();
+    let mut total = ElfTotal::new();
+
+    // an elf per paragraph, so pushing never ends one
+    for elf in paragraphs(DAY, &input) {
+        for line in elf {
+            total.push(line)?;
+        }
+        calories.extend(total.end());
+    }
+    Ok(calories)
+}
+
+pub fn read_calories(input: &InputSource) -> Result<Vec<u32>> {
+    read_calories_as(input)
 }
 
-fn read_lines(file_path: &str) -> Vec<String> {
-    let input_path = Path::new(file_path);
-    let input = fs::read_to_string(input_path).expect("Should read input file content");
+pub fn max_calories(input: &InputSource) -> Result<u32> {
+    Ok(read_calories(input)?.into_iter().max().unwrap_or_default())
+}
 
-    // Split by empty line to get
-    let lines: Vec<String> = input.clone().split("\n").map(|s| s.to_string()).collect();
-    lines
+pub fn top_3_calories(input: &InputSource) -> Result<u32> {
+    let mut calories = read_calories(input)?;
+    calories.sort_unstable_by(|a, b| b.cmp(a));
+    calories
+        .iter()
+        .take(3)
+        .try_fold(0u32, |sum, &c| sum.checked_add(c))
+        .ok_or_else(|| Error::invalid("the top 3 elves carry more calories than a u32 holds"))
 }
 
 #[cfg(test)]
 mod test_utlis {
 
     use super::*;
-    const INPUT: &str = "/home/user/dev/tutorial/adventOfCode/2022/day1/demo.txt";
+    use crate::DEMO as INPUT;
+    use num_bigint::BigUint;
 
     #[test]
     fn check_total_calories() {
-        let total = another_solution::total_calories();
-        println!("{:?}", total);
+        let total = another_solution::total_calories(&INPUT).unwrap();
+        assert_eq!(24000, total);
+        assert_eq!(40000, another_solution::top_3(&INPUT).unwrap());
     }
 
     #[test]
@@ -118,10 +196,50 @@ mod test_utlis {
 
     #[test]
     fn check_read_file() {
-        let calories = read_calories(INPUT);
+        let calories = read_calories(&INPUT).unwrap();
         assert_eq!(calories, vec![5000, 4000, 11000, 24000]);
     }
 
+    #[test]
+    fn bad_calorie() {
+        let input = InputSource::embedded("1000\n\n20OO\n");
+        let Err(Error::Parse(err)) = read_calories(&input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((3, 1, "20OO"), (err.line, err.column, err.text.as_str()));
+        assert!(err.reason.starts_with("elf #2:"));
+    }
+
+    #[test]
+    fn calories_overflow() {
+        let input = InputSource::embedded("1\n\n4000000000\n300000000\n1\n");
+        let Err(Error::Parse(err)) = read_calories(&input) else {
+            panic!("expected an overflow error");
+        };
+        assert_eq!((4, "300000000"), (err.line, err.text.as_str()));
+        assert_eq!("elf #2 carries more calories than a u32 holds", err.reason);
+
+        let wide = read_calories_as::<u64>(&input).unwrap();
+        assert_eq!(vec![1, 4300000001], wide);
+    }
+
+    #[test]
+    fn calories_as_wide() {
+        let big = u128::MAX.to_string();
+        let input = InputSource::text(format!("{big}\n1\n\n7\n"));
+        assert!(read_calories_as::<u128>(&input).is_err());
+
+        let calories = read_calories_as::<BigUint>(&input).unwrap();
+        assert_eq!(BigUint::from(u128::MAX) + 1u8, calories[0]);
+        assert_eq!(BigUint::from(7u8), calories[1]);
+    }
+
+    #[test]
+    fn check_top_calories() {
+        assert_eq!(24000, max_calories(&INPUT).unwrap());
+        assert_eq!(40000, top_3_calories(&INPUT).unwrap());
+    }
+
     fn _print_type_of<T>(_: &T) {
         println!("{}", std::any::type_name::<T>())
     }
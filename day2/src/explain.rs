@@ -0,0 +1,176 @@
+//! How every round of the guide was scored, to check a total by hand.
+
+use std::fmt::Write;
+
+use aoc_common::{numbered_lines, InputSource, Result};
+
+use crate::utils::rps;
+use crate::{Outcome, ParseMode, Round, DAY};
+
+/// A line of the guide and what it scored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Explained {
+    /// from 1
+    pub line: usize,
+    pub round: Round,
+    pub outcome: Outcome,
+    /// for the move we played
+    pub shape_points: u32,
+    pub outcome_points: u32,
+    /// the score so far, this round included
+    pub total: u32,
+}
+
+/// Every round of the guide, read as `mode` says
+pub fn explain(input: &InputSource, mode: ParseMode) -> Result<Vec<Explained>> {
+    let input = input.read()?;
+    let mut total: u32 = 0;
+    let mut rounds = Vec::new();
+
+    for line in numbered_lines(DAY, &input) {
+        let round = Round::parse(line, mode)?;
+        let outcome = round.outcome();
+        let shape_points = rps().points(round.ours);
+        let outcome_points = outcome.points();
+        total = total
+            .checked_add(shape_points + outcome_points)
+            .ok_or_else(|| line.error("the total score overflows"))?;
+
+        rounds.push(Explained {
+            line: line.number,
+            round,
+            outcome,
+            shape_points,
+            outcome_points,
+            total,
+        });
+    }
+    Ok(rounds)
+}
+
+/// The lines where reading the guide both ways has us play different moves,
+/// as `(moves, outcomes)`
+pub fn disagreements(input: &InputSource) -> Result<Vec<(Explained, Explained)>> {
+    let moves = explain(input, ParseMode::Moves)?;
+    let outcomes = explain(input, ParseMode::Outcomes)?;
+    Ok(moves
+        .into_iter()
+        .zip(outcomes)
+        .filter(|(a, b)| a.round.ours != b.round.ours)
+        .collect())
+}
+
+const COLUMNS: [&str; 7] = [
+    "line", "theirs", "ours", "outcome", "shape", "points", "total",
+];
+
+fn fields(row: &Explained) -> [String; 7] {
+    [
+        row.line.to_string(),
+        rps().name(row.round.theirs).to_string(),
+        rps().name(row.round.ours).to_string(),
+        format!("{:?}", row.outcome),
+        row.shape_points.to_string(),
+        row.outcome_points.to_string(),
+        row.total.to_string(),
+    ]
+}
+
+/// the rounds lined up in columns, with a header
+pub fn table(rows: &[Explained]) -> String {
+    let rows: Vec<[String; 7]> = rows.iter().map(fields).collect();
+    let mut widths = COLUMNS.map(str::len);
+    for row in &rows {
+        for (width, field) in widths.iter_mut().zip(row) {
+            *width = (*width).max(field.len());
+        }
+    }
+
+    let mut table = String::new();
+    let header = COLUMNS.map(String::from);
+    for row in std::iter::once(&header).chain(&rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(field, width)| format!("{field:>width$}"))
+            .collect();
+        writeln!(table, "{}", line.join("  ")).expect("writing to a String");
+    }
+    table
+}
+
+/// the rounds as CSV, with a header
+pub fn csv(rows: &[Explained]) -> String {
+    let mut csv = COLUMNS.join(",");
+    csv.push('\n');
+    for row in rows {
+        csv.push_str(&fields(row).join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+#[cfg(test)]
+mod test_explain {
+    use super::*;
+    use crate::{total_score, DEMO, INPUT};
+
+    #[test]
+    fn explain_ok() {
+        let rounds = explain(&DEMO, ParseMode::Outcomes).unwrap();
+        assert_eq!(3, rounds.len());
+        let first = rounds[0];
+        assert_eq!(
+            (1, Outcome::Draw, 1, 3, 4),
+            (
+                first.line,
+                first.outcome,
+                first.shape_points,
+                first.outcome_points,
+                first.total
+            )
+        );
+        assert_eq!(12, rounds[2].total);
+
+        for mode in ParseMode::ALL {
+            let rounds = explain(&INPUT, mode).unwrap();
+            assert_eq!(
+                total_score(&INPUT, mode).unwrap(),
+                rounds.last().unwrap().total
+            );
+        }
+    }
+
+    #[test]
+    fn disagreements_ok() {
+        // B X is rock either way, A Y and C Z aren't
+        let lines: Vec<usize> = disagreements(&DEMO)
+            .unwrap()
+            .iter()
+            .map(|(moves, _)| moves.line)
+            .collect();
+        assert_eq!(vec![1, 3], lines);
+    }
+
+    #[test]
+    fn table_and_csv_ok() {
+        let rounds = explain(&DEMO, ParseMode::Moves).unwrap();
+        let table = table(&rounds);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            "line    theirs      ours  outcome  shape  points  total",
+            lines[0]
+        );
+        assert_eq!(
+            "   1      Rock     Paper      Win      2       6      8",
+            lines[1]
+        );
+
+        let csv = csv(&rounds);
+        assert_eq!(4, csv.lines().count());
+        assert_eq!(
+            "3,Scissors,Scissors,Draw,3,3,15",
+            csv.lines().last().unwrap()
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! Rock paper scissors with any odd number of moves, the rules being data.
+
+use aoc_common::{Error, Result};
+use serde::Deserialize;
+
+/// A move of a [`Game`], by its place in the game's list of moves
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Move(pub(crate) usize);
+
+impl Move {
+    /// the moves of [`Game::rps`]
+    pub const ROCK: Move = Move(0);
+    pub const PAPER: Move = Move(1);
+    pub const SCISSORS: Move = Move(2);
+
+    /// from 0
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// How a round went, for us
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+    pub fn points(self) -> u32 {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+
+    /// the same round seen by the other player
+    pub fn reverse(self) -> Outcome {
+        match self {
+            Outcome::Loss => Outcome::Win,
+            Outcome::Draw => Outcome::Draw,
+            Outcome::Win => Outcome::Loss,
+        }
+    }
+}
+
+/// The moves of a game and which beats which.
+///
+/// Any two different moves have a winner, and every move beats as many
+/// moves as it loses to, so there's always a move to win, draw or lose
+/// against any other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    names: Vec<String>,
+    // beats[a][b]: move a beats move b
+    beats: Vec<Vec<bool>>,
+}
+
+impl Game {
+    /// A game from its moves and `(winner, loser)` pairs of move indices
+    pub fn new(names: Vec<String>, wins: &[(usize, usize)]) -> Result<Game> {
+        let n = names.len();
+        if n < 3 || n.is_multiple_of(2) {
+            return Err(Error::invalid(format!(
+                "a game needs an odd number of moves, at least 3, not {n}"
+            )));
+        }
+
+        let mut beats = vec![vec![false; n]; n];
+        for &(winner, loser) in wins {
+            if winner >= n || loser >= n {
+                return Err(Error::invalid(format!(
+                    "no move {} in a game of {n}",
+                    winner.max(loser)
+                )));
+            }
+            if winner == loser {
+                return Err(Error::invalid(format!(
+                    "{} can't beat itself",
+                    names[winner]
+                )));
+            }
+            beats[winner][loser] = true;
+        }
+
+        for a in 0..n {
+            for b in a + 1..n {
+                if beats[a][b] == beats[b][a] {
+                    return Err(Error::invalid(format!(
+                        "exactly one of {} and {} has to win",
+                        names[a], names[b]
+                    )));
+                }
+            }
+            let wins = beats[a].iter().filter(|&&b| b).count();
+            if wins != n / 2 {
+                return Err(Error::invalid(format!(
+                    "{} beats {wins} moves, every move has to beat {}",
+                    names[a],
+                    n / 2
+                )));
+            }
+        }
+        Ok(Game { names, beats })
+    }
+
+    /// The moves in order, each beating the ones an odd number of places
+    /// before it, going round from the first to the last.
+    pub fn cyclic<S: Into<String>>(names: impl IntoIterator<Item = S>) -> Result<Game> {
+        let names: Vec<String> = names.into_iter().map(Into::into).collect();
+        let n = names.len();
+        let mut wins = Vec::new();
+        for a in 0..n {
+            for b in 0..n {
+                if (a + n - b) % n % 2 == 1 {
+                    wins.push((a, b));
+                }
+            }
+        }
+        Game::new(names, &wins)
+    }
+
+    /// rock, paper, scissors
+    pub fn rps() -> Game {
+        Game::cyclic(["Rock", "Paper", "Scissors"]).expect("3 moves make a game")
+    }
+
+    /// rock, paper, scissors, Spock, lizard
+    pub fn rpsls() -> Game {
+        Game::cyclic(["Rock", "Paper", "Scissors", "Spock", "Lizard"]).expect("5 moves make a game")
+    }
+
+    /// number of moves
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn moves(&self) -> impl Iterator<Item = Move> {
+        (0..self.len()).map(Move)
+    }
+
+    pub fn get(&self, index: usize) -> Option<Move> {
+        (index < self.len()).then_some(Move(index))
+    }
+
+    /// the move called `name`, ignoring case
+    pub fn find(&self, name: &str) -> Option<Move> {
+        self.names
+            .iter()
+            .position(|n| n.eq_ignore_ascii_case(name))
+            .map(Move)
+    }
+
+    pub fn name(&self, m: Move) -> &str {
+        &self.names[m.0]
+    }
+
+    pub fn beats(&self, a: Move, b: Move) -> bool {
+        self.beats[a.0][b.0]
+    }
+
+    pub fn outcome(&self, ours: Move, theirs: Move) -> Outcome {
+        if self.beats(ours, theirs) {
+            Outcome::Win
+        } else if self.beats(theirs, ours) {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// The move giving `outcome` against `theirs`, the first in the list
+    /// if several do
+    pub fn matching_move(&self, outcome: Outcome, theirs: Move) -> Move {
+        self.moves()
+            .find(|&ours| self.outcome(ours, theirs) == outcome)
+            .expect("every move has one to win, draw and lose against")
+    }
+
+    /// what playing `m` is worth: its place in the list, from 1
+    pub fn points(&self, m: Move) -> u32 {
+        m.0 as u32 + 1
+    }
+
+    /// our score for the round
+    pub fn score(&self, ours: Move, theirs: Move) -> u32 {
+        self.points(ours) + self.outcome(ours, theirs).points()
+    }
+}
+
+#[cfg(test)]
+mod test_game {
+    use super::*;
+
+    #[test]
+    fn rps_ok() {
+        let game = Game::rps();
+        let [rock, paper, scissors] = ["rock", "paper", "scissors"].map(|m| game.find(m).unwrap());
+        assert!(game.beats(paper, rock));
+        assert!(game.beats(scissors, paper));
+        assert!(game.beats(rock, scissors));
+
+        assert_eq!(8, game.score(paper, rock));
+        assert_eq!(1, game.score(rock, paper));
+        assert_eq!(6, game.score(scissors, scissors));
+    }
+
+    #[test]
+    fn rpsls_ok() {
+        let game = Game::rpsls();
+        let find = |name| game.find(name).unwrap();
+        // as Sheldon explains it
+        for (winner, loser) in [
+            ("scissors", "paper"),
+            ("paper", "rock"),
+            ("rock", "lizard"),
+            ("lizard", "spock"),
+            ("spock", "scissors"),
+            ("scissors", "lizard"),
+            ("lizard", "paper"),
+            ("paper", "spock"),
+            ("spock", "rock"),
+            ("rock", "scissors"),
+        ] {
+            assert!(
+                game.beats(find(winner), find(loser)),
+                "{winner} beats {loser}"
+            );
+            assert!(!game.beats(find(loser), find(winner)));
+        }
+    }
+
+    #[test]
+    fn matching_move_ok() {
+        for game in [
+            Game::rps(),
+            Game::rpsls(),
+            Game::cyclic((1..=7).map(|i| i.to_string())).unwrap(),
+        ] {
+            for theirs in game.moves() {
+                for outcome in Outcome::ALL {
+                    let ours = game.matching_move(outcome, theirs);
+                    assert_eq!(outcome, game.outcome(ours, theirs));
+                    assert_eq!(outcome.reverse(), game.outcome(theirs, ours));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bad_games() {
+        assert!(Game::cyclic(["a", "b", "c", "d"]).is_err());
+        assert!(Game::cyclic(["a"]).is_err());
+
+        let names = || ["a", "b", "c"].map(String::from).to_vec();
+        // a beats both, c beats none
+        assert!(Game::new(names(), &[(0, 1), (0, 2), (1, 2)]).is_err());
+        assert!(Game::new(names(), &[(0, 1), (1, 2)]).is_err());
+        assert!(Game::new(names(), &[(0, 0), (1, 2), (2, 0)]).is_err());
+        assert!(Game::new(names(), &[(0, 3)]).is_err());
+        assert!(Game::new(names(), &[(1, 0), (2, 1), (0, 2)]).is_ok());
+    }
+}
@@ -0,0 +1,36 @@
+//! Seeded synthetic inputs, for stress testing the solvers.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// `size` rounds like "A Y"
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut input = String::with_capacity(size * 4);
+
+    for _ in 0..size {
+        input.push((b'A' + rng.gen_range(0..3)) as char);
+        input.push(' ');
+        input.push((b'X' + rng.gen_range(0..3)) as char);
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod test_gen {
+    use super::*;
+    use crate::{total_score, ParseMode};
+    use aoc_common::InputSource;
+
+    #[test]
+    fn generate_ok() {
+        let input = generate(7, 500);
+        assert_eq!(input, generate(7, 500));
+        assert_eq!(500, input.lines().count());
+
+        let input = InputSource::text(input);
+        assert!(total_score(&input, ParseMode::Moves).is_ok());
+        assert!(total_score(&input, ParseMode::Outcomes).is_ok());
+    }
+}
@@ -0,0 +1,17 @@
+pub mod explain;
+pub mod game;
+pub mod gen;
+pub mod mixed;
+pub mod optimise;
+pub mod player;
+pub mod rules;
+pub mod stream;
+pub mod tournament;
+mod utils;
+pub use utils::*;
+
+use aoc_common::InputSource;
+
+pub const DAY: u8 = 2;
+pub const INPUT: InputSource = InputSource::embedded(include_str!("input.txt"));
+pub const DEMO: InputSource = InputSource::embedded(include_str!("../demo.txt"));
@@ -1,12 +1,125 @@
-mod utils;
-use utils::*;
+use std::path::PathBuf;
 
-const INPUT: &str = "/home/user/dev/tutorial/adventOfCode/2022/day2/src/input.txt";
+use aoc_common::InputSource;
+use clap::{Parser, ValueEnum};
+use day2::rules::Rules;
+use day2::*;
 
-fn main() {
-    let mistaken_total_score = total_score(INPUT, read_round);
+/// Both totals of the strategy guide, plus whichever reports are asked for
+#[derive(Parser)]
+#[command(name = "day2", about = "Day 2: Rock Paper Scissors")]
+struct Cli {
+    /// every round, both ways
+    #[arg(long)]
+    explain: bool,
+    /// every round, one way, as CSV
+    #[arg(long, value_enum, value_name = "MODE")]
+    csv: Option<Mode>,
+    /// a guide with mixes like "A:0.5,B:0.5", scored on average
+    #[arg(long, value_name = "FILE")]
+    mixed: Option<PathBuf>,
+    /// the same guide scored with the rules of a TOML file
+    #[arg(long, value_name = "FILE")]
+    rules: Option<PathBuf>,
+    /// the best reading of the guide for us and the worst for them
+    #[arg(long)]
+    optimise: bool,
+    /// a Markov player against a frequency counter, 1000 rounds
+    #[arg(long)]
+    tournament: bool,
+    /// the Nash equilibrium of the standard game
+    #[arg(long)]
+    nash: bool,
+}
+
+/// what X, Y and Z mean
+#[derive(Clone, Copy, ValueEnum)]
+enum Mode {
+    Moves,
+    Outcomes,
+}
+
+impl From<Mode> for ParseMode {
+    fn from(mode: Mode) -> ParseMode {
+        match mode {
+            Mode::Moves => ParseMode::Moves,
+            Mode::Outcomes => ParseMode::Outcomes,
+        }
+    }
+}
+
+fn main() -> aoc_common::Result<()> {
+    let cli = Cli::parse();
+
+    let mistaken_total_score = total_score(&INPUT, ParseMode::Moves)?;
     println!("First Strategy Total: {}", mistaken_total_score);
 
-    let diff_total_score = total_score(INPUT, diff_read_round);
+    let diff_total_score = total_score(&INPUT, ParseMode::Outcomes)?;
     println!("Second Strategy Total: {}", diff_total_score);
+
+    let standard = Rules::standard();
+    let game = standard.game();
+    if cli.optimise {
+        let all = optimise::interpretations(&standard, &INPUT)?;
+        if let Some(best) = optimise::best_for_us(&all) {
+            println!(
+                "Best reading for us: {} ({})",
+                best.describe(game),
+                best.ours
+            );
+        }
+        if let Some(worst) = optimise::worst_for_them(&all) {
+            println!(
+                "Worst reading for them: {} ({})",
+                worst.describe(game),
+                worst.theirs
+            );
+        }
+    }
+
+    if cli.tournament {
+        let mut markov = player::Markov::default();
+        let mut counter = player::FrequencyCounter::default();
+        println!(
+            "{}",
+            tournament::play(&standard, &mut markov, &mut counter, 1000)
+        );
+    }
+
+    if cli.nash {
+        if let Some(nash) = mixed::nash(&standard) {
+            println!(
+                "Nash equilibrium: {}, scoring {:.2} a round",
+                nash.strategy.describe(game),
+                nash.value
+            );
+        }
+    }
+
+    if cli.explain {
+        for mode in ParseMode::ALL {
+            let rounds = explain::explain(&INPUT, mode)?;
+            println!("{mode:?}:\n{}", explain::table(&rounds));
+        }
+    }
+    if let Some(mode) = cli.csv {
+        print!("{}", explain::csv(&explain::explain(&INPUT, mode.into())?));
+    }
+
+    if let Some(path) = cli.mixed {
+        let guide = InputSource::Path(path);
+        for mode in ParseMode::ALL {
+            let expected = mixed::expected_total(&standard, &guide, mode)?;
+            println!("{mode:?} expected total: {expected}");
+        }
+    }
+
+    if let Some(path) = cli.rules {
+        let rules = Rules::load(path)?;
+        let first = rules.total_score(&INPUT, ParseMode::Moves)?;
+        println!("First Strategy Total with those rules: {}", first);
+        let second = rules.total_score(&INPUT, ParseMode::Outcomes)?;
+        println!("Second Strategy Total with those rules: {}", second);
+    }
+    Ok(())
 }
@@ -0,0 +1,470 @@
+//! Strategy guides where either symbol can be left to chance, like
+//! "A:0.25,C:0.75 Y", and what such a guide scores on average.
+
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+
+use aoc_common::{numbered_lines, Error, InputLine, InputSource, Result};
+
+use crate::game::{Game, Move};
+use crate::rules::Rules;
+use crate::utils::split_round;
+use crate::{ParseMode, Round, DAY};
+
+// how far off 1 probabilities may add up to, and the like
+const EPSILON: f64 = 1e-9;
+
+/// How likely each move of a game is
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mixed(Vec<f64>);
+
+impl Mixed {
+    /// By move index, none negative and adding up to 1
+    pub fn new(probabilities: Vec<f64>) -> Result<Mixed> {
+        if probabilities.iter().any(|p| !(0.0..=1.0).contains(p)) {
+            return Err(Error::invalid(format!(
+                "not all probabilities in {probabilities:?} are between 0 and 1"
+            )));
+        }
+        let total: f64 = probabilities.iter().sum();
+        if (total - 1.0).abs() > EPSILON {
+            return Err(Error::invalid(format!(
+                "probabilities add up to {total}, not 1"
+            )));
+        }
+        Ok(Mixed(probabilities))
+    }
+
+    /// always `m`
+    pub fn pure(game: &Game, m: Move) -> Mixed {
+        Mixed(
+            game.moves()
+                .map(|n| if n == m { 1.0 } else { 0.0 })
+                .collect(),
+        )
+    }
+
+    /// any move, all as likely
+    pub fn uniform(game: &Game) -> Mixed {
+        Mixed(vec![1.0 / game.len() as f64; game.len()])
+    }
+
+    pub fn probability(&self, m: Move) -> f64 {
+        self.0.get(m.index()).copied().unwrap_or_default()
+    }
+
+    /// the moves that can happen, with how likely they are
+    pub fn support(&self) -> impl Iterator<Item = (Move, f64)> + '_ {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, &p)| p > 0.0)
+            .map(|(i, &p)| (Move(i), p))
+    }
+
+    /// our score for a round playing this against `theirs`
+    pub fn against(&self, rules: &Rules, theirs: &Mixed) -> Expectation {
+        MixedRound::new(self, theirs).expected(rules)
+    }
+
+    /// like "Rock 50.0%, Paper 50.0%", the moves that can't happen left out
+    pub fn describe(&self, game: &Game) -> String {
+        let moves: Vec<String> = self
+            .support()
+            .map(|(m, p)| format!("{} {:.1}%", game.name(m), p * 100.0))
+            .collect();
+        moves.join(", ")
+    }
+}
+
+/// The mean and variance of a score
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Expectation {
+    pub mean: f64,
+    pub variance: f64,
+}
+
+impl Expectation {
+    /// of a score that comes out as each `(probability, score)`
+    pub fn of(scores: impl IntoIterator<Item = (f64, f64)> + Clone) -> Expectation {
+        let mean: f64 = scores.clone().into_iter().map(|(p, s)| p * s).sum();
+        let variance = scores
+            .into_iter()
+            .map(|(p, s)| p * (s - mean).powi(2))
+            .sum();
+        Expectation { mean, variance }
+    }
+
+    pub fn std_dev(self) -> f64 {
+        self.variance.sqrt()
+    }
+}
+
+/// the sum of two independent scores
+impl Add for Expectation {
+    type Output = Expectation;
+
+    fn add(self, other: Expectation) -> Expectation {
+        Expectation {
+            mean: self.mean + other.mean,
+            variance: self.variance + other.variance,
+        }
+    }
+}
+
+impl Sum for Expectation {
+    fn sum<I: Iterator<Item = Expectation>>(iter: I) -> Expectation {
+        iter.fold(Expectation::default(), Add::add)
+    }
+}
+
+impl fmt::Display for Expectation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2} (sd {:.2})", self.mean, self.std_dev())
+    }
+}
+
+/// A round that could go several ways
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixedRound {
+    // each way, and how likely it is
+    rounds: Vec<(Round, f64)>,
+}
+
+impl MixedRound {
+    /// both sides picking a move on their own
+    pub fn new(ours: &Mixed, theirs: &Mixed) -> MixedRound {
+        let rounds = theirs
+            .support()
+            .flat_map(|(theirs, q)| {
+                ours.support()
+                    .map(move |(ours, p)| (Round { theirs, ours }, p * q))
+            })
+            .collect();
+        MixedRound { rounds }
+    }
+
+    /// A line of the guide, each symbol either plain ("A") or a mix of
+    /// symbols and their probabilities ("A:0.5,B:0.5"); the second one is
+    /// read as `mode` says
+    pub fn read(rules: &Rules, line: InputLine, mode: ParseMode) -> Result<MixedRound> {
+        let (first, second) = split_round(line)?;
+        let theirs = read_mix(line, first, |s| rules.their_move(line, s))?;
+
+        let mut rounds = Vec::new();
+        match mode {
+            ParseMode::Moves => {
+                let ours = read_mix(line, second, |s| rules.our_move(line, s))?;
+                for &(theirs, q) in &theirs {
+                    for &(ours, p) in &ours {
+                        rounds.push((Round { theirs, ours }, p * q));
+                    }
+                }
+            }
+            ParseMode::Outcomes => {
+                let outcomes = read_mix(line, second, |s| rules.outcome(line, s))?;
+                for &(theirs, q) in &theirs {
+                    for &(outcome, p) in &outcomes {
+                        let ours = rules.game().matching_move(outcome, theirs);
+                        rounds.push((Round { theirs, ours }, p * q));
+                    }
+                }
+            }
+        }
+        Ok(MixedRound { rounds })
+    }
+
+    /// every way the round could go, and how likely it is
+    pub fn rounds(&self) -> &[(Round, f64)] {
+        &self.rounds
+    }
+
+    /// our score for the round
+    pub fn expected(&self, rules: &Rules) -> Expectation {
+        Expectation::of(
+            self.rounds
+                .iter()
+                .map(|&(Round { theirs, ours }, p)| (p, rules.score(ours, theirs) as f64)),
+        )
+    }
+}
+
+// "A:0.25,B:0.75", or just "A"
+fn read_mix<'a, T>(
+    line: InputLine<'a>,
+    text: &'a str,
+    read: impl Fn(&'a str) -> Result<T>,
+) -> Result<Vec<(T, f64)>> {
+    let mut mix = Vec::new();
+    let mut total = 0.0;
+    for part in text.split(',') {
+        let (symbol, p) = match part.split_once(':') {
+            Some((symbol, p)) => {
+                let p = p
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|p| (0.0..=1.0).contains(p))
+                    .ok_or_else(|| line.error_at(p, "not a probability"))?;
+                (symbol, p)
+            }
+            None => (part, 1.0),
+        };
+        total += p;
+        mix.push((read(symbol)?, p));
+    }
+    if (total - 1.0).abs() > EPSILON {
+        return Err(line.error_at(text, format!("probabilities add up to {total}, not 1")));
+    }
+    Ok(mix)
+}
+
+/// Our score over the whole guide, lines being independent of each other
+pub fn expected_total(rules: &Rules, input: &InputSource, mode: ParseMode) -> Result<Expectation> {
+    let input = input.read()?;
+    numbered_lines(DAY, &input)
+        .map(|line| Ok(MixedRound::read(rules, line, mode)?.expected(rules)))
+        .sum()
+}
+
+/// A strategy both sides can play with neither doing better by changing
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equilibrium {
+    pub strategy: Mixed,
+    /// what each side scores a round on average
+    pub value: f64,
+}
+
+/// the most moves [`nash`] tries every support of, 2^n of them
+pub const NASH_MAX_MOVES: usize = 15;
+
+/// The symmetric Nash equilibrium of the game as `rules` score it, found by
+/// trying the supports smallest first.
+///
+/// `None` for games of more than [`NASH_MAX_MOVES`] moves, or if no support
+/// gives a single answer.
+pub fn nash(rules: &Rules) -> Option<Equilibrium> {
+    let n = rules.game().len();
+    if n > NASH_MAX_MOVES {
+        return None;
+    }
+    let payoff = |i: usize, j: usize| rules.score(Move(i), Move(j)) as f64;
+
+    let mut supports: Vec<u64> = (1..1 << n).collect();
+    supports.sort_by_key(|support| support.count_ones());
+    supports
+        .into_iter()
+        .find_map(|support| equalising(n, support, payoff))
+}
+
+// the strategy on `support` scoring the same for each of its moves, if no
+// move does better against it
+fn equalising(n: usize, support: u64, payoff: impl Fn(usize, usize) -> f64) -> Option<Equilibrium> {
+    let moves: Vec<usize> = (0..n).filter(|i| support >> i & 1 == 1).collect();
+    let k = moves.len();
+
+    // unknowns: the probabilities of `moves`, then the value
+    let mut rows: Vec<Vec<f64>> = moves
+        .iter()
+        .map(|&i| {
+            let mut row: Vec<f64> = moves.iter().map(|&j| payoff(i, j)).collect();
+            row.extend([-1.0, 0.0]);
+            row
+        })
+        .collect();
+    let mut adds_up = vec![1.0; k];
+    adds_up.extend([0.0, 1.0]);
+    rows.push(adds_up);
+
+    let solution = solve(rows)?;
+    let value = solution[k];
+    if solution[..k].iter().any(|&p| p < -EPSILON) {
+        return None;
+    }
+    let mut probabilities = vec![0.0; n];
+    for (&i, &p) in moves.iter().zip(&solution) {
+        probabilities[i] = p.max(0.0);
+    }
+
+    let better = (0..n).any(|i| {
+        let score: f64 = (0..n).map(|j| payoff(i, j) * probabilities[j]).sum();
+        score > value + EPSILON
+    });
+    (!better).then_some(Equilibrium {
+        strategy: Mixed(probabilities),
+        value,
+    })
+}
+
+// Gauss-Jordan on an augmented matrix, `None` if it has no single solution
+fn solve(mut rows: Vec<Vec<f64>>) -> Option<Vec<f64>> {
+    let n = rows.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&a, &b| rows[a][col].abs().total_cmp(&rows[b][col].abs()))?;
+        if rows[pivot][col].abs() < EPSILON {
+            return None;
+        }
+        rows.swap(col, pivot);
+        let pivot = rows[col].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != col {
+                let factor = row[col] / pivot[col];
+                for (x, p) in row.iter_mut().zip(&pivot).skip(col) {
+                    *x -= factor * p;
+                }
+            }
+        }
+    }
+    Some(
+        rows.iter()
+            .enumerate()
+            .map(|(r, row)| row[n] / row[r])
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod test_mixed {
+    use super::*;
+    use crate::{total_score, DEMO};
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn plain_guide_ok() {
+        let rules = Rules::standard();
+        for mode in ParseMode::ALL {
+            let expected = expected_total(&rules, &DEMO, mode).unwrap();
+            assert_eq!(total_score(&DEMO, mode).unwrap() as f64, expected.mean);
+            assert_eq!(0.0, expected.variance);
+        }
+    }
+
+    #[test]
+    fn mixed_line_ok() {
+        let rules = Rules::standard();
+        // paper against rock (8) or paper (5)
+        let input = InputSource::text("A:0.5,B:0.5 Y");
+        let expected = expected_total(&rules, &input, ParseMode::Moves).unwrap();
+        assert_eq!((6.5, 2.25), (expected.mean, expected.variance));
+
+        // a win against rock (8) or a loss against it (3), twice
+        let input = InputSource::text("A X:0.25,Z:0.75\nA X:0.25,Z:0.75");
+        let expected = expected_total(&rules, &input, ParseMode::Outcomes).unwrap();
+        assert!(close(13.5, expected.mean), "{expected}");
+        assert!(close(2.0 * 0.1875 * 25.0, expected.variance), "{expected}");
+    }
+
+    #[test]
+    fn bad_mix() {
+        let rules = Rules::standard();
+        let line = |text| InputLine::new(DAY, 1, text);
+
+        let Err(Error::Parse(err)) =
+            MixedRound::read(&rules, line("A:0.5,B:0.4 X"), ParseMode::Moves)
+        else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((1, "A:0.5,B:0.4"), (err.column, err.text.as_str()));
+
+        let Err(Error::Parse(err)) = MixedRound::read(&rules, line("A X:2,Y:-1"), ParseMode::Moves)
+        else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((5, "2"), (err.column, err.text.as_str()));
+
+        let Err(Error::Parse(err)) =
+            MixedRound::read(&rules, line("A X:0.5,W:0.5"), ParseMode::Moves)
+        else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((9, "W"), (err.column, err.text.as_str()));
+
+        assert!(Mixed::new(vec![0.5, 0.25]).is_err());
+    }
+
+    #[test]
+    fn nash_ok() {
+        // rock 4/9, paper 1/9, scissors 4/9: each scores 5 against it
+        let rules = Rules::standard();
+        let equilibrium = nash(&rules).unwrap();
+        let game = rules.game();
+        assert!(close(5.0, equilibrium.value));
+        for (m, p) in [(Move::ROCK, 4.0), (Move::PAPER, 1.0), (Move::SCISSORS, 4.0)] {
+            assert!(
+                close(p / 9.0, equilibrium.strategy.probability(m)),
+                "{equilibrium:?}"
+            );
+        }
+        assert_eq!(
+            "Rock 44.4%, Paper 11.1%, Scissors 44.4%",
+            equilibrium.strategy.describe(game)
+        );
+    }
+
+    #[test]
+    fn nash_is_an_equilibrium() {
+        let rules = Rules::parse(
+            r#"
+            moves = ["Rock", "Paper", "Scissors", "Spock", "Lizard"]
+            [scoring.moves]
+            Rock = 1
+            Paper = 2
+            Scissors = 3
+            Spock = 4
+            Lizard = 5
+            [scoring.outcomes]
+            loss = 0
+            draw = 3
+            win = 6
+            [symbols]
+            theirs = {}
+            ours = {}
+            outcomes = {}
+            "#,
+        )
+        .unwrap();
+        let equilibrium = nash(&rules).unwrap();
+        let game = rules.game();
+        assert!(close(
+            equilibrium.value,
+            equilibrium
+                .strategy
+                .against(&rules, &equilibrium.strategy)
+                .mean
+        ));
+        for m in game.moves() {
+            let score = Mixed::pure(game, m)
+                .against(&rules, &equilibrium.strategy)
+                .mean;
+            assert!(score <= equilibrium.value + 1e-6, "{m:?} scores {score}");
+        }
+
+        // too many supports to try
+        let moves: Vec<String> = (0..NASH_MAX_MOVES + 2).map(|i| format!("m{i}")).collect();
+        let points: Vec<String> = moves.iter().map(|m| format!("{m} = 1")).collect();
+        let rules = Rules::parse(&format!(
+            "moves = {moves:?}\n[scoring.moves]\n{}\n\
+             [scoring.outcomes]\nloss = 0\ndraw = 3\nwin = 6\n\
+             [symbols]\ntheirs = {{}}\nours = {{}}\noutcomes = {{}}\n",
+            points.join("\n")
+        ))
+        .unwrap();
+        assert_eq!(None, nash(&rules));
+
+        // with every move worth the same, nothing beats picking at random
+        let rules = Rules::parse(
+            &include_str!("../rules.toml")
+                .replace("Paper = 2", "Paper = 1")
+                .replace("Scissors = 3", "Scissors = 1"),
+        )
+        .unwrap();
+        let equilibrium = nash(&rules).unwrap();
+        assert_eq!(
+            Mixed::uniform(rules.game()).describe(rules.game()),
+            equilibrium.strategy.describe(rules.game())
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Every way the second column of the guide could be read, best first.
+//!
+//! The elf never said what X, Y and Z mean. Each of them could be any move
+//! or any outcome, as long as no two letters mean the same thing.
+
+use std::collections::BTreeMap;
+
+use aoc_common::{numbered_lines, Error, InputSource, Result};
+use itertools::Itertools;
+
+use crate::game::{Game, Move, Outcome};
+use crate::rules::Rules;
+use crate::DAY;
+
+/// What one of our letters stands for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Meaning {
+    Move(Move),
+    Outcome(Outcome),
+}
+
+/// A reading of the guide, and what it scores for both players
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interpretation {
+    /// each letter of the second column, in order, with its meaning
+    pub symbols: Vec<(String, Meaning)>,
+    pub ours: u32,
+    pub theirs: u32,
+}
+
+impl Interpretation {
+    /// e.g. "X=Rock Y=Paper Z=Scissors"
+    pub fn describe(&self, game: &Game) -> String {
+        self.symbols
+            .iter()
+            .map(|(symbol, meaning)| match meaning {
+                Meaning::Move(m) => format!("{symbol}={}", game.name(*m)),
+                Meaning::Outcome(o) => format!("{symbol}={o:?}"),
+            })
+            .join(" ")
+    }
+}
+
+/// Every reading of the guide, by our score, most first, then by theirs,
+/// least first
+pub fn interpretations(rules: &Rules, input: &InputSource) -> Result<Vec<Interpretation>> {
+    let input = input.read()?;
+    // the same round scores the same, so only count them
+    let mut rounds: BTreeMap<(Move, &str), u32> = BTreeMap::new();
+    for line in numbered_lines(DAY, &input) {
+        *rounds.entry(rules.read_symbols(line)?).or_default() += 1;
+    }
+    let symbols: Vec<&str> = rounds.keys().map(|&(_, s)| s).unique().sorted().collect();
+
+    let game = rules.game();
+    if symbols.len() > game.len() {
+        return Err(Error::invalid(format!(
+            "{} letters for our side, but only {} moves",
+            symbols.len(),
+            game.len()
+        )));
+    }
+
+    let as_moves = game
+        .moves()
+        .permutations(symbols.len())
+        .map(|moves| moves.into_iter().map(Meaning::Move).collect_vec());
+    let as_outcomes = Outcome::ALL
+        .into_iter()
+        .permutations(symbols.len())
+        .map(|outcomes| outcomes.into_iter().map(Meaning::Outcome).collect_vec());
+
+    let mut all: Vec<Interpretation> = as_moves
+        .chain(as_outcomes)
+        .map(|meanings| {
+            let mut ours = 0;
+            let mut theirs = 0;
+            for (&(their_move, symbol), &count) in &rounds {
+                let i = symbols
+                    .binary_search(&symbol)
+                    .expect("every symbol is listed");
+                let our_move = match meanings[i] {
+                    Meaning::Move(m) => m,
+                    Meaning::Outcome(outcome) => game.matching_move(outcome, their_move),
+                };
+                ours = add_rounds(ours, count, rules.score(our_move, their_move))?;
+                theirs = add_rounds(theirs, count, rules.score(their_move, our_move))?;
+            }
+            Ok(Interpretation {
+                symbols: symbols
+                    .iter()
+                    .map(|s| s.to_string())
+                    .zip(meanings)
+                    .collect(),
+                ours,
+                theirs,
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    all.sort_by_key(|i| (std::cmp::Reverse(i.ours), i.theirs));
+    Ok(all)
+}
+
+// `total` plus `count` rounds scoring `score` each
+fn add_rounds(total: u32, count: u32, score: u32) -> Result<u32> {
+    count
+        .checked_mul(score)
+        .and_then(|points| total.checked_add(points))
+        .ok_or_else(|| Error::invalid("the total score overflows"))
+}
+
+/// the reading that scores us the most
+pub fn best_for_us(interpretations: &[Interpretation]) -> Option<&Interpretation> {
+    interpretations.first()
+}
+
+/// the reading that scores the opponent the least
+pub fn worst_for_them(interpretations: &[Interpretation]) -> Option<&Interpretation> {
+    interpretations.iter().min_by_key(|i| i.theirs)
+}
+
+#[cfg(test)]
+mod test_optimise {
+    use super::*;
+    use crate::{total_score, ParseMode, DEMO, INPUT};
+
+    fn find<'a>(all: &'a [Interpretation], game: &Game, reading: &str) -> &'a Interpretation {
+        all.iter().find(|i| i.describe(game) == reading).unwrap()
+    }
+
+    #[test]
+    fn interpretations_ok() {
+        let rules = Rules::standard();
+        let game = rules.game();
+        for input in [DEMO, INPUT] {
+            let all = interpretations(&rules, &input).unwrap();
+            // 3! as moves, 3! as outcomes
+            assert_eq!(12, all.len());
+
+            let part1 = find(&all, game, "X=Rock Y=Paper Z=Scissors");
+            assert_eq!(total_score(&input, ParseMode::Moves).unwrap(), part1.ours);
+            let part2 = find(&all, game, "X=Loss Y=Draw Z=Win");
+            assert_eq!(
+                total_score(&input, ParseMode::Outcomes).unwrap(),
+                part2.ours
+            );
+
+            assert!(all.windows(2).all(|w| w[0].ours >= w[1].ours));
+        }
+    }
+
+    #[test]
+    fn best_and_worst_ok() {
+        let rules = Rules::standard();
+        let all = interpretations(&rules, &DEMO).unwrap();
+
+        // A Y, B X, C Z: winning every round is only possible as moves
+        let best = best_for_us(&all).unwrap();
+        assert_eq!("X=Scissors Y=Paper Z=Rock", best.describe(rules.game()));
+        assert_eq!((24, 6), (best.ours, best.theirs));
+        assert_eq!(6, worst_for_them(&all).unwrap().theirs);
+    }
+
+    #[test]
+    fn overflowing_scores() {
+        let rules =
+            Rules::parse(&include_str!("../rules.toml").replace("Rock = 1", "Rock = 4000000000"))
+                .unwrap();
+        let input = InputSource::text("A X\nA X");
+        assert!(matches!(
+            interpretations(&rules, &input),
+            Err(Error::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn too_many_letters() {
+        let rules = Rules::standard();
+        let input = InputSource::text("A X\nA Y\nA Z\nA W");
+        assert!(interpretations(&rules, &input).is_err());
+    }
+}
@@ -0,0 +1,177 @@
+//! Ways to pick the next move, for playing strategies against each other.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::game::{Game, Move, Outcome};
+
+/// Someone playing round after round, maybe learning from the other side
+pub trait Player {
+    fn name(&self) -> String;
+
+    /// the move for the next round
+    fn play(&mut self, game: &Game) -> Move;
+
+    /// what both sides played in the round just gone
+    fn observe(&mut self, _game: &Game, _ours: Move, _theirs: Move) {}
+}
+
+/// always the same move
+pub struct Fixed(pub Move);
+
+impl Player for Fixed {
+    fn name(&self) -> String {
+        format!("fixed {}", self.0.index())
+    }
+
+    fn play(&mut self, _game: &Game) -> Move {
+        self.0
+    }
+}
+
+/// any move, the same ones for the same seed
+pub struct Random {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Random {
+        Random {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Player for Random {
+    fn name(&self) -> String {
+        format!("random {}", self.seed)
+    }
+
+    fn play(&mut self, game: &Game) -> Move {
+        Move(self.rng.gen_range(0..game.len()))
+    }
+}
+
+/// beats whatever the other side played most so far
+#[derive(Default)]
+pub struct FrequencyCounter {
+    counts: Vec<u32>,
+}
+
+impl Player for FrequencyCounter {
+    fn name(&self) -> String {
+        "frequency counter".into()
+    }
+
+    fn play(&mut self, game: &Game) -> Move {
+        match most_played(&self.counts) {
+            Some(expected) => game.matching_move(Outcome::Win, expected),
+            None => Move(0),
+        }
+    }
+
+    fn observe(&mut self, game: &Game, _ours: Move, theirs: Move) {
+        self.counts.resize(game.len(), 0);
+        self.counts[theirs.index()] += 1;
+    }
+}
+
+/// Beats what the other side played most often after their last move
+#[derive(Default)]
+pub struct Markov {
+    // follows[a][b]: times they played b right after a
+    follows: Vec<Vec<u32>>,
+    last: Option<Move>,
+}
+
+impl Player for Markov {
+    fn name(&self) -> String {
+        "markov".into()
+    }
+
+    fn play(&mut self, game: &Game) -> Move {
+        let expected = self
+            .last
+            .and_then(|last| most_played(&self.follows[last.index()]));
+        match expected {
+            Some(expected) => game.matching_move(Outcome::Win, expected),
+            None => Move(0),
+        }
+    }
+
+    fn observe(&mut self, game: &Game, _ours: Move, theirs: Move) {
+        self.follows.resize(game.len(), vec![0; game.len()]);
+        if let Some(last) = self.last {
+            self.follows[last.index()][theirs.index()] += 1;
+        }
+        self.last = Some(theirs);
+    }
+}
+
+/// plays what the other side just played
+#[derive(Default)]
+pub struct CopyLast {
+    last: Option<Move>,
+}
+
+impl Player for CopyLast {
+    fn name(&self) -> String {
+        "copy last".into()
+    }
+
+    fn play(&mut self, _game: &Game) -> Move {
+        self.last.unwrap_or(Move(0))
+    }
+
+    fn observe(&mut self, _game: &Game, _ours: Move, theirs: Move) {
+        self.last = Some(theirs);
+    }
+}
+
+// the most counted move, the first one on a tie, `None` before any count
+fn most_played(counts: &[u32]) -> Option<Move> {
+    let max = *counts.iter().max()?;
+    (max > 0).then(|| Move(counts.iter().position(|&c| c == max).unwrap_or_default()))
+}
+
+#[cfg(test)]
+mod test_player {
+    use super::*;
+
+    #[test]
+    fn learners_ok() {
+        let game = Game::rps();
+        let [rock, paper, scissors] = [0, 1, 2].map(Move);
+
+        let mut counter = FrequencyCounter::default();
+        assert_eq!(rock, counter.play(&game));
+        for theirs in [scissors, rock, scissors] {
+            counter.observe(&game, rock, theirs);
+        }
+        assert_eq!(rock, counter.play(&game));
+
+        // they go rock, paper, scissors, rock, ...
+        let mut markov = Markov::default();
+        for theirs in [rock, paper, scissors, rock] {
+            markov.observe(&game, rock, theirs);
+        }
+        assert_eq!(scissors, markov.play(&game));
+
+        let mut copy = CopyLast::default();
+        copy.observe(&game, rock, paper);
+        assert_eq!(paper, copy.play(&game));
+    }
+
+    #[test]
+    fn random_ok() {
+        let game = Game::rpsls();
+        let moves = |seed| {
+            let mut random = Random::new(seed);
+            (0..50).map(|_| random.play(&game)).collect::<Vec<_>>()
+        };
+        assert_eq!(moves(3), moves(3));
+        assert!(moves(3).iter().all(|m| m.index() < 5));
+    }
+}
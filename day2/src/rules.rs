@@ -0,0 +1,321 @@
+//! Scoring and strategy guide letters as configuration, see `rules.toml`.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use aoc_common::{numbered_lines, Error, InputLine, InputSource, Result};
+use serde::Deserialize;
+
+use crate::game::{Game, Move, Outcome};
+use crate::utils::split_round;
+use crate::{ParseMode, Round, DAY};
+
+/// Points for a round, by move name and by outcome
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScoringRules {
+    pub moves: BTreeMap<String, u32>,
+    pub outcomes: OutcomePoints,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OutcomePoints {
+    pub loss: u32,
+    pub draw: u32,
+    pub win: u32,
+}
+
+impl OutcomePoints {
+    pub fn get(&self, outcome: Outcome) -> u32 {
+        match outcome {
+            Outcome::Loss => self.loss,
+            Outcome::Draw => self.draw,
+            Outcome::Win => self.win,
+        }
+    }
+}
+
+/// What the letters of the strategy guide stand for
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SymbolMap {
+    /// first column: their move
+    pub theirs: BTreeMap<String, String>,
+    /// second column as our move
+    pub ours: BTreeMap<String, String>,
+    /// second column as the outcome to aim for
+    pub outcomes: BTreeMap<String, Outcome>,
+}
+
+// rules.toml as written
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RulesFile {
+    moves: Option<Vec<String>>,
+    scoring: ScoringRules,
+    symbols: SymbolMap,
+}
+
+/// A game with its scoring and symbols, checked against each other
+#[derive(Debug, Clone)]
+pub struct Rules {
+    game: Game,
+    scoring: ScoringRules,
+    // by move index
+    move_points: Vec<u32>,
+    theirs: BTreeMap<String, Move>,
+    ours: BTreeMap<String, Move>,
+    outcomes: BTreeMap<String, Outcome>,
+}
+
+impl Rules {
+    const BUNDLED: &'static str = include_str!("../rules.toml");
+
+    /// the puzzle's rules
+    pub fn standard() -> Rules {
+        Rules::parse(Rules::BUNDLED).expect("rules.toml is valid")
+    }
+
+    pub fn parse(text: &str) -> Result<Rules> {
+        let file: RulesFile =
+            toml::from_str(text).map_err(|e| Error::invalid(format!("bad rules: {e}")))?;
+        let game = match file.moves {
+            Some(moves) => Game::cyclic(moves)?,
+            None => Game::rps(),
+        };
+        Rules::new(game, file.scoring, file.symbols)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Rules> {
+        Rules::parse(&aoc_common::read_input(path)?)
+    }
+
+    /// Every move of `game` needs points, no round scoring more than a
+    /// `u32` holds, and every symbol has to name one of its moves
+    pub fn new(game: Game, scoring: ScoringRules, symbols: SymbolMap) -> Result<Rules> {
+        let find = |name: &String| {
+            game.find(name)
+                .ok_or_else(|| Error::invalid(format!("no move {name:?} in the game")))
+        };
+
+        for name in scoring.moves.keys() {
+            find(name)?;
+        }
+        let move_points = game
+            .moves()
+            .map(|m| {
+                let name = game.name(m);
+                scoring
+                    .moves
+                    .iter()
+                    .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                    .map(|(_, &points)| points)
+                    .ok_or_else(|| Error::invalid(format!("no points for playing {name}")))
+            })
+            .collect::<Result<Vec<u32>>>()?;
+
+        // so that no round's score overflows
+        let outcomes = &scoring.outcomes;
+        let most = move_points.iter().max().copied().unwrap_or_default();
+        if most
+            .checked_add(outcomes.loss.max(outcomes.draw).max(outcomes.win))
+            .is_none()
+        {
+            return Err(Error::invalid(
+                "a round can score more points than fit a u32",
+            ));
+        }
+
+        let moves = |symbols: &BTreeMap<String, String>| {
+            symbols
+                .iter()
+                .map(|(symbol, name)| Ok((symbol.clone(), find(name)?)))
+                .collect::<Result<BTreeMap<_, _>>>()
+        };
+        let theirs = moves(&symbols.theirs)?;
+        let ours = moves(&symbols.ours)?;
+
+        Ok(Rules {
+            game,
+            scoring,
+            move_points,
+            theirs,
+            ours,
+            outcomes: symbols.outcomes,
+        })
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    pub fn scoring(&self) -> &ScoringRules {
+        &self.scoring
+    }
+
+    /// our score for the round
+    pub fn score(&self, ours: Move, theirs: Move) -> u32 {
+        let outcome = self.game.outcome(ours, theirs);
+        self.move_points[ours.index()] + self.scoring.outcomes.get(outcome)
+    }
+
+    /// A line of the guide, the second symbol read as `mode` says
+    pub fn read_round(&self, line: InputLine, mode: ParseMode) -> Result<Round> {
+        let (first, second) = split_round(line)?;
+        let theirs = self.their_move(line, first)?;
+        let ours = match mode {
+            ParseMode::Moves => self.our_move(line, second)?,
+            ParseMode::Outcomes => self.game.matching_move(self.outcome(line, second)?, theirs),
+        };
+        Ok(Round { theirs, ours })
+    }
+
+    /// their move and our symbol, whatever it means
+    pub(crate) fn read_symbols<'a>(&self, line: InputLine<'a>) -> Result<(Move, &'a str)> {
+        let (first, second) = split_round(line)?;
+        Ok((self.their_move(line, first)?, second))
+    }
+
+    pub(crate) fn their_move(&self, line: InputLine, s: &str) -> Result<Move> {
+        symbol(line, s, &self.theirs, "not their move")
+    }
+
+    pub(crate) fn our_move(&self, line: InputLine, s: &str) -> Result<Move> {
+        symbol(line, s, &self.ours, "not our move")
+    }
+
+    pub(crate) fn outcome(&self, line: InputLine, s: &str) -> Result<Outcome> {
+        symbol(line, s, &self.outcomes, "not an outcome")
+    }
+
+    /// the score of the whole guide, read as `mode` says
+    pub fn total_score(&self, input: &InputSource, mode: ParseMode) -> Result<u32> {
+        let input = input.read()?;
+        let mut total: u32 = 0;
+        for line in numbered_lines(DAY, &input) {
+            let Round { theirs, ours } = self.read_round(line, mode)?;
+            total = total
+                .checked_add(self.score(ours, theirs))
+                .ok_or_else(|| line.error("the total score overflows"))?;
+        }
+        Ok(total)
+    }
+}
+
+fn symbol<T: Copy>(
+    line: InputLine,
+    symbol: &str,
+    symbols: &BTreeMap<String, T>,
+    reason: &str,
+) -> Result<T> {
+    symbols
+        .get(symbol)
+        .copied()
+        .ok_or_else(|| line.error_at(symbol, reason))
+}
+
+#[cfg(test)]
+mod test_rules {
+    use super::*;
+    use crate::{total_score, DEMO, INPUT};
+
+    #[test]
+    fn standard_matches_utils() {
+        let rules = Rules::standard();
+        for input in [DEMO, INPUT] {
+            for mode in ParseMode::ALL {
+                assert_eq!(
+                    total_score(&input, mode).unwrap(),
+                    rules.total_score(&input, mode).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn other_rules_ok() {
+        // letters swapped round, and a win only worth a point more
+        let rules = Rules::parse(
+            r#"
+            [scoring]
+            moves = { rock = 1, paper = 2, scissors = 3 }
+            outcomes = { loss = 0, draw = 0, win = 1 }
+
+            [symbols]
+            theirs = { R = "Rock", P = "Paper", S = "Scissors" }
+            ours = { X = "Scissors", Y = "Paper", Z = "Rock" }
+            outcomes = { X = "win", Y = "draw", Z = "loss" }
+            "#,
+        )
+        .unwrap();
+
+        let input = InputSource::text("R Y\nP X\nS Z");
+        // paper beats rock, scissors beat paper, rock beats scissors
+        assert_eq!(
+            2 + 1 + 3 + 1 + 1 + 1,
+            rules.total_score(&input, ParseMode::Moves).unwrap()
+        );
+        // rock to draw, scissors to win, paper to lose
+        assert_eq!(
+            1 + 3 + 1 + 2,
+            rules.total_score(&input, ParseMode::Outcomes).unwrap()
+        );
+    }
+
+    #[test]
+    fn rpsls_rules_ok() {
+        let rules = Rules::parse(
+            r#"
+            moves = ["Rock", "Paper", "Scissors", "Spock", "Lizard"]
+            [scoring]
+            moves = { Rock = 1, Paper = 2, Scissors = 3, Spock = 4, Lizard = 5 }
+            outcomes = { loss = 0, draw = 3, win = 6 }
+            [symbols]
+            theirs = { A = "Rock", E = "Lizard" }
+            ours = { V = "Spock" }
+            outcomes = { Z = "win" }
+            "#,
+        )
+        .unwrap();
+
+        // lizard poisons Spock
+        let input = InputSource::text("E V\nA V");
+        assert_eq!(
+            4 + 4 + 6,
+            rules.total_score(&input, ParseMode::Moves).unwrap()
+        );
+    }
+
+    #[test]
+    fn bad_rules() {
+        let standard = Rules::BUNDLED;
+        assert!(Rules::parse(&standard.replace("Scissors = 3", "")).is_err());
+        assert!(Rules::parse(&standard.replace("C = \"Scissors\"", "C = \"Lizard\"")).is_err());
+        assert!(Rules::parse(&standard.replace("Z = \"win\"", "Z = \"victory\"")).is_err());
+        assert!(
+            Rules::parse(&standard.replace("[scoring.outcomes]", "[scoring.outcome]")).is_err()
+        );
+        assert!(Rules::parse(&standard.replace("win = 6", "win = 4294967295")).is_err());
+    }
+
+    #[test]
+    fn bad_symbol() {
+        let rules = Rules::standard();
+        let Err(Error::Parse(err)) =
+            rules.read_round(InputLine::new(DAY, 4, "A W"), ParseMode::Moves)
+        else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((4, 3, "W"), (err.line, err.column, err.text.as_str()));
+
+        // split the same as without rules
+        for line in ["A  Y", " A Y", "A\tY"] {
+            let line = InputLine::new(DAY, 1, line);
+            for mode in ParseMode::ALL {
+                assert!(rules.read_round(line, mode).is_err());
+                assert!(Round::parse(line, mode).is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Solvers reading the input line by line, in constant memory.
+
+use std::io::BufRead;
+
+use aoc_common::{for_each_line, Result};
+
+use crate::utils::add_round;
+use crate::{ParseMode, DAY};
+
+pub fn total_score(reader: impl BufRead, mode: ParseMode) -> Result<u32> {
+    let mut total: u32 = 0;
+    for_each_line(DAY, reader, |line| {
+        total = add_round(total, line, mode)?;
+        Ok(())
+    })?;
+    Ok(total)
+}
+
+#[cfg(test)]
+mod test_stream {
+    use super::*;
+    use crate::{gen, DEMO, INPUT};
+    use aoc_common::InputSource;
+
+    #[test]
+    fn stream_matches_total_score() {
+        let generated = InputSource::text(gen::generate(3, 1000));
+        for input in [DEMO, INPUT, generated] {
+            for mode in ParseMode::ALL {
+                let reader = input.reader().unwrap();
+                assert_eq!(
+                    crate::total_score(&input, mode).unwrap(),
+                    total_score(reader, mode).unwrap()
+                );
+            }
+        }
+    }
+}
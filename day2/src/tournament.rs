@@ -0,0 +1,140 @@
+//! Two players, many rounds, and how it went for each.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::game::Outcome;
+use crate::player::Player;
+use crate::rules::Rules;
+
+/// How a match went for one side
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerStats {
+    pub name: String,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    pub total: u64,
+    /// how many rounds scored how much
+    pub scores: BTreeMap<u32, u32>,
+}
+
+impl PlayerStats {
+    fn record(&mut self, outcome: Outcome, score: u32) {
+        match outcome {
+            Outcome::Win => self.wins += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::Loss => self.losses += 1,
+        }
+        self.total += score as u64;
+        *self.scores.entry(score).or_default() += 1;
+    }
+}
+
+impl fmt::Display for PlayerStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} won, {} drawn, {} lost, {} points (",
+            self.name, self.wins, self.draws, self.losses, self.total
+        )?;
+        for (i, (score, rounds)) in self.scores.iter().enumerate() {
+            let sep = if i > 0 { ", " } else { "" };
+            write!(f, "{sep}{score}: {rounds}x")?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub rounds: u32,
+    pub first: PlayerStats,
+    pub second: PlayerStats,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} rounds", self.rounds)?;
+        writeln!(f, "  {}", self.first)?;
+        write!(f, "  {}", self.second)
+    }
+}
+
+/// `rounds` rounds of `first` against `second`, scored by `rules`
+pub fn play(rules: &Rules, first: &mut dyn Player, second: &mut dyn Player, rounds: u32) -> Report {
+    let game = rules.game();
+    let mut report = Report {
+        rounds,
+        first: PlayerStats {
+            name: first.name(),
+            ..PlayerStats::default()
+        },
+        second: PlayerStats {
+            name: second.name(),
+            ..PlayerStats::default()
+        },
+    };
+
+    for _ in 0..rounds {
+        let a = first.play(game);
+        let b = second.play(game);
+
+        let outcome = game.outcome(a, b);
+        report.first.record(outcome, rules.score(a, b));
+        report.second.record(outcome.reverse(), rules.score(b, a));
+
+        first.observe(game, a, b);
+        second.observe(game, b, a);
+    }
+    report
+}
+
+#[cfg(test)]
+mod test_tournament {
+    use super::*;
+    use crate::game::Move;
+    use crate::player::{CopyLast, Fixed, FrequencyCounter, Markov, Random};
+
+    #[test]
+    fn fixed_ok() {
+        let rules = Rules::standard();
+        // rock beats scissors every time
+        let report = play(&rules, &mut Fixed(Move(0)), &mut Fixed(Move(2)), 10);
+        assert_eq!(
+            (10, 0, 0),
+            (report.first.wins, report.first.draws, report.first.losses)
+        );
+        assert_eq!(BTreeMap::from([(7, 10)]), report.first.scores);
+        assert_eq!(BTreeMap::from([(3, 10)]), report.second.scores);
+        assert_eq!(30, report.second.total);
+    }
+
+    #[test]
+    fn learners_beat_fixed() {
+        let rules = Rules::standard();
+        let players: [&mut dyn Player; 2] =
+            [&mut FrequencyCounter::default(), &mut Markov::default()];
+        for learner in players {
+            let report = play(&rules, learner, &mut Fixed(Move(1)), 100);
+            // both need a round or two to catch on
+            assert!(report.first.wins >= 98, "{report}");
+        }
+
+        let report = play(&rules, &mut CopyLast::default(), &mut Fixed(Move(1)), 100);
+        assert_eq!(99, report.first.draws);
+    }
+
+    #[test]
+    fn report_adds_up() {
+        let rules = Rules::standard();
+        let report = play(&rules, &mut Random::new(1), &mut Markov::default(), 500);
+        let first = &report.first;
+        assert_eq!(500, first.wins + first.draws + first.losses);
+        assert_eq!(first.wins, report.second.losses);
+        assert_eq!(500, first.scores.values().sum::<u32>());
+
+        let again = play(&rules, &mut Random::new(1), &mut Markov::default(), 500);
+        assert_eq!(report, again);
+    }
+}
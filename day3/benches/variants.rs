@@ -0,0 +1,36 @@
+// cargo bench -p day3, report in target/criterion/report/index.html
+// every variant reads the input itself, so they all pay for the same copy
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day3::{another_solution, get_badges_sum, total_priority_sum, INPUT};
+
+fn priorities(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day3/priorities");
+    group.bench_function("ItemSet", |b| {
+        b.iter(|| total_priority_sum(black_box(&INPUT)).unwrap())
+    });
+    group.bench_function("Vec<Item>", |b| {
+        b.iter(|| another_solution::part1(&black_box(&INPUT).read().unwrap()).unwrap())
+    });
+    group.finish();
+}
+
+fn badges(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day3/badges");
+    group.bench_function("ItemSet", |b| {
+        b.iter(|| get_badges_sum(black_box(&INPUT)).unwrap())
+    });
+    group.bench_function("HashSet<Item>", |b| {
+        b.iter(|| another_solution::part2_hashset(&black_box(&INPUT).read().unwrap()).unwrap())
+    });
+    group.bench_function("im::HashSet<Item>", |b| {
+        b.iter(|| another_solution::part2_im_hashset(&black_box(&INPUT).read().unwrap()).unwrap())
+    });
+    group.bench_function("[u8; 53]", |b| {
+        b.iter(|| another_solution::part2_u8_array(&black_box(&INPUT).read().unwrap()).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, priorities, badges);
+criterion_main!(benches);
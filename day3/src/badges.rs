@@ -0,0 +1,210 @@
+//! Badges of groups of any number of rucksacks.
+
+use std::fmt;
+
+use aoc_common::{numbered_lines, Error, InputLine, InputSource, Result};
+
+use crate::item::{Item, ItemSet};
+use crate::utils::{add_priority, check_items, items};
+use crate::DAY;
+
+/// Why a group has no badge, groups counting from 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BadgeError {
+    /// no item in every rucksack of the group
+    Missing { group: usize },
+    /// more than one item in every rucksack of the group
+    Several { group: usize, items: ItemSet },
+    /// the input ended `rucksacks` rucksacks into the group
+    Partial {
+        group: usize,
+        rucksacks: usize,
+        size: usize,
+    },
+}
+
+impl BadgeError {
+    pub fn group(&self) -> usize {
+        match *self {
+            BadgeError::Missing { group }
+            | BadgeError::Several { group, .. }
+            | BadgeError::Partial { group, .. } => group,
+        }
+    }
+}
+
+impl fmt::Display for BadgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BadgeError::Missing { group } => {
+                write!(f, "group #{group}: no item in every rucksack")
+            }
+            BadgeError::Several { group, items } => write!(
+                f,
+                "group #{group}: {} items in every rucksack, {items:?}",
+                items.len()
+            ),
+            BadgeError::Partial {
+                group,
+                rucksacks,
+                size,
+            } => write!(f, "group #{group}: only {rucksacks} of {size} rucksacks"),
+        }
+    }
+}
+
+impl std::error::Error for BadgeError {}
+
+/// A group's badge, or why it hasn't one
+pub type Badge = std::result::Result<Item, BadgeError>;
+
+/// Finds the one item carried by all the rucksacks of each group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BadgeFinder {
+    size: usize,
+}
+
+/// groups of 3, as in the puzzle
+impl Default for BadgeFinder {
+    fn default() -> Self {
+        BadgeFinder { size: 3 }
+    }
+}
+
+impl BadgeFinder {
+    /// groups of `size` rucksacks, in input order
+    pub fn new(size: usize) -> Result<BadgeFinder> {
+        if size == 0 {
+            return Err(Error::invalid("a group needs at least one rucksack"));
+        }
+        Ok(BadgeFinder { size })
+    }
+
+    pub fn size(self) -> usize {
+        self.size
+    }
+
+    /// The badge of every group, a short one at the end included; fails
+    /// only on rucksacks that aren't all letters
+    pub fn badges(self, input: &InputSource) -> Result<Vec<Badge>> {
+        let input = input.read()?;
+        let lines: Vec<InputLine> = numbered_lines(DAY, &input).collect();
+        lines
+            .chunks(self.size)
+            .enumerate()
+            .map(|(i, group)| self.badge(i + 1, group))
+            .collect()
+    }
+
+    /// The priorities of the badges added up, failing on the first group
+    /// without exactly one, or past a `u32`
+    pub fn sum(self, input: &InputSource) -> Result<u32> {
+        let input = input.read()?;
+        let lines: Vec<InputLine> = numbered_lines(DAY, &input).collect();
+        let mut total = 0;
+        for (i, group) in lines.chunks(self.size).enumerate() {
+            total = add_priority(total, self.checked_badge(i + 1, group)?, group[0])?;
+        }
+        Ok(total)
+    }
+
+    /// the badge of the `index`th group
+    pub(crate) fn badge(self, index: usize, group: &[InputLine]) -> Result<Badge> {
+        for line in group {
+            check_items(*line)?;
+        }
+        if group.len() < self.size {
+            return Ok(Err(BadgeError::Partial {
+                group: index,
+                rucksacks: group.len(),
+                size: self.size,
+            }));
+        }
+
+        let common = group
+            .iter()
+            .map(|line| items(line.text))
+            .reduce(ItemSet::intersection)
+            .unwrap_or_default();
+        Ok(match common.len() {
+            0 => Err(BadgeError::Missing { group: index }),
+            1 => Ok(common.iter().next().expect("one item")),
+            _ => Err(BadgeError::Several {
+                group: index,
+                items: common,
+            }),
+        })
+    }
+
+    // the badge, a group without one being an error at its first line
+    pub(crate) fn checked_badge(self, index: usize, group: &[InputLine]) -> Result<Item> {
+        self.badge(index, group)?
+            .map_err(|err| group[0].error(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test_badges {
+    use super::*;
+    use crate::DEMO;
+
+    const GROUPS: &str = "abc\nbcd\nbxy\nab\nab\nq";
+
+    #[test]
+    fn sum_ok() {
+        assert_eq!(70, BadgeFinder::default().sum(&DEMO).unwrap());
+        assert_eq!(
+            2,
+            BadgeFinder::new(4)
+                .unwrap()
+                .sum(&InputSource::text("abc\nbcd\nbxy\nab"))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn badges_ok() {
+        let input = InputSource::text(GROUPS);
+        let badges = BadgeFinder::new(2).unwrap().badges(&input).unwrap();
+        let b = Item::try_from(b'b').unwrap();
+        let c = Item::try_from(b'c').unwrap();
+        assert_eq!(
+            vec![
+                Err(BadgeError::Several {
+                    group: 1,
+                    items: ItemSet::from_iter([b, c])
+                }),
+                Ok(b),
+                Err(BadgeError::Missing { group: 3 }),
+            ],
+            badges
+        );
+
+        // the full group still counts
+        let badges = BadgeFinder::new(4).unwrap().badges(&input).unwrap();
+        assert_eq!(Ok(b), badges[0]);
+        let partial = badges[1].unwrap_err();
+        assert_eq!(2, partial.group());
+        assert_eq!("group #2: only 2 of 4 rucksacks", partial.to_string());
+    }
+
+    #[test]
+    fn bad_groups() {
+        assert!(BadgeFinder::new(0).is_err());
+
+        let input = InputSource::text(GROUPS);
+        let Err(Error::Parse(err)) = BadgeFinder::new(2).unwrap().sum(&input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(
+            (1, "group #1: 2 items in every rucksack, {b, c}"),
+            (err.line, err.reason.as_str())
+        );
+
+        let input = InputSource::text("ab\nb1");
+        let Err(Error::Parse(err)) = BadgeFinder::new(2).unwrap().badges(&input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((2, 2), (err.line, err.column));
+    }
+}
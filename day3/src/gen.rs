@@ -0,0 +1,96 @@
+//! Seeded synthetic inputs, for stress testing the solvers.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+const ITEMS: &[u8; 52] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// `size` groups of 3 rucksacks.
+///
+/// Every rucksack has exactly one item type in both compartments, and every
+/// group exactly one badge carried by all three elves.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let mut items = ITEMS.to_vec();
+        items.shuffle(&mut rng);
+        let badge = items.pop().unwrap();
+
+        // 3 pools of 17, each rucksack draws from two of them, so no other
+        // item can end up in all three rucksacks
+        let pools: Vec<&[u8]> = items.chunks(17).collect();
+        for i in 0..3 {
+            let allowed = [pools[i], pools[(i + 1) % 3]].concat();
+            input.push_str(&rucksack(&mut rng, badge, allowed));
+            input.push('\n');
+        }
+    }
+    input
+}
+
+// a rucksack carrying `badge` once, filled up with `allowed` items
+fn rucksack(rng: &mut StdRng, badge: u8, mut allowed: Vec<u8>) -> String {
+    allowed.shuffle(rng);
+    let shared = allowed.pop().unwrap();
+
+    // the others only ever go in one of the compartments
+    let split = rng.gen_range(1..allowed.len());
+    let (left_only, right_only) = allowed.split_at(split);
+    let len = rng.gen_range(2..=16);
+    let badge_left = rng.gen_bool(0.5);
+
+    let mut items = compartment(rng, len, shared, badge_left.then_some(badge), left_only);
+    items.extend(compartment(
+        rng,
+        len,
+        shared,
+        (!badge_left).then_some(badge),
+        right_only,
+    ));
+    String::from_utf8(items).unwrap()
+}
+
+fn compartment(
+    rng: &mut StdRng,
+    len: usize,
+    shared: u8,
+    badge: Option<u8>,
+    only: &[u8],
+) -> Vec<u8> {
+    let mut items = vec![shared];
+    items.extend(badge);
+    while items.len() < len {
+        items.push(*only.choose(rng).unwrap());
+    }
+    items.shuffle(rng);
+    items
+}
+
+#[cfg(test)]
+mod test_gen {
+    use super::*;
+    use crate::{another_solution, get_badges_sum, total_priority_sum};
+    use aoc_common::InputSource;
+
+    #[test]
+    fn generate_ok() {
+        let input = generate(7, 200);
+        assert_eq!(input, generate(7, 200));
+        assert_eq!(600, input.lines().count());
+
+        let source = InputSource::text(input.clone());
+        let priorities = total_priority_sum(&source).unwrap();
+        assert_eq!(
+            priorities as usize,
+            another_solution::part1(&input).unwrap()
+        );
+
+        let badges = get_badges_sum(&source).unwrap() as usize;
+        assert_eq!(badges, another_solution::part2_hashset(&input).unwrap());
+        assert_eq!(badges, another_solution::part2_im_hashset(&input).unwrap());
+        assert_eq!(badges, another_solution::part2_u8_array(&input).unwrap());
+    }
+}
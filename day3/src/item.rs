@@ -0,0 +1,204 @@
+//! Rucksack items, and sets of them as a bit per item.
+
+use std::fmt;
+
+use aoc_common::Error;
+
+/// An item in a rucksack, a letter
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Item(u8);
+
+impl TryFrom<u8> for Item {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'a'..=b'z' | b'A'..=b'Z' => Ok(Item(value)),
+            _ => Err(Error::invalid(format!(
+                "{} is not a valid item",
+                value as char
+            ))),
+        }
+    }
+}
+
+impl From<Item> for char {
+    fn from(item: Item) -> char {
+        item.0 as char
+    }
+}
+
+impl fmt::Debug for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0 as char)
+    }
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0 as char)
+    }
+}
+
+impl Item {
+    /// a to z are 1 to 26, A to Z 27 to 52
+    pub fn priority(self) -> u32 {
+        match self.0 {
+            b'a'..=b'z' => 1 + (self.0 - b'a') as u32,
+            _ => 27 + (self.0 - b'A') as u32,
+        }
+    }
+
+    // the item of priority `bit + 1`
+    fn from_bit(bit: u32) -> Item {
+        match bit {
+            0..=25 => Item(b'a' + bit as u8),
+            _ => Item(b'A' + (bit - 26) as u8),
+        }
+    }
+
+    fn bit(self) -> u64 {
+        1 << (self.priority() - 1)
+    }
+}
+
+/// Items, each there or not, as bits of a `u64` in priority order
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    /// `false` if it was there already
+    pub fn insert(&mut self, item: Item) -> bool {
+        let new = !self.contains(item);
+        self.0 |= item.bit();
+        new
+    }
+
+    pub fn contains(self, item: Item) -> bool {
+        self.0 & item.bit() != 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    /// the items not in `other`
+    pub fn difference(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & !other.0)
+    }
+
+    /// lowest priority first
+    pub fn iter(self) -> Iter {
+        Iter(self.0)
+    }
+
+    pub fn priority_sum(self) -> u32 {
+        self.iter().map(Item::priority).sum()
+    }
+}
+
+impl FromIterator<Item> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = Item>>(items: I) -> ItemSet {
+        let mut set = ItemSet::EMPTY;
+        for item in items {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+impl IntoIterator for ItemSet {
+    type Item = Item;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl fmt::Debug for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// The items of an [`ItemSet`], lowest priority first
+#[derive(Debug, Clone)]
+pub struct Iter(u64);
+
+impl Iterator for Iter {
+    type Item = Item;
+
+    fn next(&mut self) -> Option<Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = self.0.trailing_zeros();
+        // clears the lowest bit
+        self.0 &= self.0 - 1;
+        Some(Item::from_bit(bit))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+#[cfg(test)]
+mod test_item {
+    use super::*;
+
+    fn set(items: &str) -> ItemSet {
+        items.bytes().map(|b| Item::try_from(b).unwrap()).collect()
+    }
+
+    #[test]
+    fn priority_ok() {
+        let item = |b| Item::try_from(b).unwrap();
+        assert_eq!(1, item(b'a').priority());
+        assert_eq!(26, item(b'z').priority());
+        assert_eq!(27, item(b'A').priority());
+        assert_eq!(52, item(b'Z').priority());
+        assert!(Item::try_from(b'1').is_err());
+
+        for bit in 0..52 {
+            assert_eq!(bit + 1, Item::from_bit(bit).priority());
+        }
+    }
+
+    #[test]
+    fn set_ok() {
+        let a = set("vJrwpWtwJgWr");
+        assert_eq!(8, a.len());
+        assert!(a.contains(Item(b'W')) && !a.contains(Item(b'x')));
+
+        let b = set("hcsFMMfFFhFp");
+        assert_eq!(set("p"), a.intersection(b));
+        assert_eq!(set("vJrwWtgphcsFMf"), a.union(b));
+        assert_eq!(set("vJrwWtg"), a.difference(b));
+        assert!(a.difference(a).is_empty());
+
+        let items: String = set("zAaZ").iter().map(char::from).collect();
+        assert_eq!("azAZ", items);
+        assert_eq!(1 + 26 + 27 + 52, set("zAaZ").priority_sum());
+        assert_eq!("{a, Z}", format!("{:?}", set("Za")));
+    }
+}
@@ -0,0 +1,13 @@
+pub mod badges;
+pub mod gen;
+pub mod item;
+pub mod stream;
+mod utils;
+pub mod validate;
+pub use utils::*;
+
+use aoc_common::InputSource;
+
+pub const DAY: u8 = 3;
+pub const INPUT: InputSource = InputSource::embedded(include_str!("input.txt"));
+pub const DEMO: InputSource = InputSource::embedded(include_str!("../demo.txt"));
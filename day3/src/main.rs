@@ -1,9 +1,30 @@
-mod utils;
-use utils::{total_priority_sum, get_badges_sum};
-const INPUT: &str = "/home/user/dev/tutorial/adventOfCode/2022/day3/src/input.txt";
+use day3::badges::BadgeFinder;
+use day3::validate::validate;
+use day3::{get_badges_sum, total_priority_sum, INPUT};
 
+fn main() -> aoc_common::Result<()> {
+    println!("Total: {}", total_priority_sum(&INPUT)?);
+    println!("Badges sum: {}", get_badges_sum(&INPUT)?);
 
-fn main() {
-    println!("Total: {}", total_priority_sum(INPUT));
-    println!("Badges sum: {}", get_badges_sum(INPUT));
+    // day3 --validate: everything off about each rucksack
+    if std::env::args().any(|arg| arg == "--validate") {
+        println!("{}", validate(&INPUT)?);
+    }
+
+    // day3 --group N: badges of groups of N, and the groups without one
+    let mut args = std::env::args().skip_while(|arg| arg != "--group").skip(1);
+    if let Some(size) = args.next() {
+        let size = size
+            .parse()
+            .map_err(|_| aoc_common::Error::invalid(format!("not a group size: {size}")))?;
+        let mut total = 0;
+        for badge in BadgeFinder::new(size)?.badges(&INPUT)? {
+            match badge {
+                Ok(item) => total += item.priority(),
+                Err(err) => println!("{err}"),
+            }
+        }
+        println!("Badges sum in groups of {size}: {total}");
+    }
+    Ok(())
 }
@@ -0,0 +1,81 @@
+//! Solvers reading the input line by line, in constant memory.
+
+use std::io::BufRead;
+
+use aoc_common::{for_each_line, InputLine, Result};
+
+use crate::badges::BadgeFinder;
+use crate::utils::{add_priority, shared_item};
+use crate::DAY;
+
+pub fn total_priority_sum(reader: impl BufRead) -> Result<u32> {
+    let mut total = 0;
+    for_each_line(DAY, reader, |line| {
+        total = add_priority(total, shared_item(line)?, line)?;
+        Ok(())
+    })?;
+    Ok(total)
+}
+
+pub fn get_badges_sum(reader: impl BufRead) -> Result<u32> {
+    let finder = BadgeFinder::default();
+    let mut total = 0;
+    let mut index = 1;
+    // the group read so far, as (line number, rucksack)
+    let mut group: Vec<(usize, String)> = Vec::with_capacity(finder.size());
+
+    for_each_line(DAY, reader, |line| {
+        group.push((line.number, line.text.to_string()));
+        if group.len() == finder.size() {
+            let rucksacks = lines(&group);
+            let badge = finder.checked_badge(index, &rucksacks)?;
+            total = add_priority(total, badge, rucksacks[0])?;
+            index += 1;
+            group.clear();
+        }
+        Ok(())
+    })?;
+
+    if !group.is_empty() {
+        // reports the incomplete group
+        finder.checked_badge(index, &lines(&group))?;
+    }
+    Ok(total)
+}
+
+fn lines(group: &[(usize, String)]) -> Vec<InputLine<'_>> {
+    group
+        .iter()
+        .map(|(number, text)| InputLine::new(DAY, *number, text))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_stream {
+    use super::*;
+    use crate::{gen, DEMO, INPUT};
+    use aoc_common::InputSource;
+
+    #[test]
+    fn stream_matches_utils() {
+        let generated = InputSource::text(gen::generate(3, 300));
+        for input in [DEMO, INPUT, generated] {
+            let reader = input.reader().unwrap();
+            assert_eq!(
+                crate::total_priority_sum(&input).unwrap(),
+                total_priority_sum(reader).unwrap()
+            );
+            let reader = input.reader().unwrap();
+            assert_eq!(
+                crate::get_badges_sum(&input).unwrap(),
+                get_badges_sum(reader).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn incomplete_group() {
+        let err = get_badges_sum("abca\nacdc\n".as_bytes()).unwrap_err();
+        assert!(matches!(err, aoc_common::Error::Parse(_)));
+    }
+}
@@ -1,58 +1,60 @@
-#![allow(unused)]
-use std::collections::HashSet;
-use std::fs;
-use std::path::Path;
+use aoc_common::{numbered_lines, InputLine, InputSource, Result};
 
-pub fn total_priority_sum(input_path: &str) -> u32 {
-    let lines = read_lines(input_path);
+use crate::badges::BadgeFinder;
+use crate::item::{Item, ItemSet};
+use crate::DAY;
+
+pub fn total_priority_sum(input: &InputSource) -> Result<u32> {
+    let input = input.read()?;
     let mut total = 0;
 
-    for line in lines.iter() {
-        let c = shared_item(line);
-        let val = priority_val(c);
-        total += val;
+    for line in numbered_lines(DAY, &input) {
+        total = add_priority(total, shared_item(line)?, line)?;
     }
-    total
+    Ok(total)
 }
 
-pub fn get_badges_sum(file_path: &str) -> u32 {
-    let lines = read_lines(file_path);
-    let length = lines.len();
-    let mut badges: Vec<char> = Vec::new();
-
-    let mut i = 0;
-    while i != length {
-        let r1 = &lines[i];
-        let r2 = &lines[i + 1];
-        let r3 = &lines[i + 2];
-
-        let set: HashSet<char> = r1.chars().collect();
-        let shared: Vec<char> = r2.chars().filter(|c| set.contains(c)).collect();
-        let commons: Vec<char> = r3.chars().filter(|c| shared.contains(c)).collect();
-
-        badges.push(commons[0]);
-        i += 3;
-    }
+/// badges of groups of 3, see [`BadgeFinder`] for other sizes
+pub fn get_badges_sum(input: &InputSource) -> Result<u32> {
+    BadgeFinder::default().sum(input)
+}
 
-    badges.into_iter().map(|c| priority_val(c)).sum()
+// `total` plus the item's priority, an error at `line` past a u32
+pub(crate) fn add_priority(total: u32, item: Item, line: InputLine) -> Result<u32> {
+    total
+        .checked_add(item.priority())
+        .ok_or_else(|| line.error("the priorities add up to more than a u32 holds"))
 }
 
-fn priority_val(c: char) -> u32 {
-    if c.is_ascii_lowercase() {
-        c as u32 - 96
-    } else {
-        c as u32 - 38
+// items are letters only
+pub(crate) fn check_items(line: InputLine) -> Result<()> {
+    match line
+        .text
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_alphabetic())
+    {
+        Some((i, c)) => Err(line.error_at(&line.text[i..i + c.len_utf8()], "not an item")),
+        None => Ok(()),
     }
 }
 
-fn shared_item(rucksack: &str) -> char {
-    let compartments = compartments(rucksack);
-    let n = compartments.0.len();
+pub(crate) fn shared_item(line: InputLine) -> Result<Item> {
+    check_items(line)?;
+    let (first, second) = compartments(line.text);
+    let shared = items(first).intersection(items(second));
 
-    let mut set: HashSet<char> = compartments.0.chars().collect();
-    let mut shared: Vec<char> = compartments.1.chars().filter(|c| set.contains(c)).collect();
+    match shared.iter().next() {
+        Some(item) => Ok(item),
+        None => Err(line.error("no item shared by both compartments")),
+    }
+}
 
-    shared[0]
+// the items of a rucksack already checked to be letters only
+pub(crate) fn items(rucksack: &str) -> ItemSet {
+    rucksack
+        .bytes()
+        .filter_map(|b| Item::try_from(b).ok())
+        .collect()
 }
 
 fn compartments(rucksack: &str) -> (&str, &str) {
@@ -60,232 +62,254 @@ fn compartments(rucksack: &str) -> (&str, &str) {
     rucksack.split_at(length / 2)
 }
 
-fn read_lines(file_path: &str) -> Vec<String> {
-    let input_path = Path::new(file_path);
-    let input = fs::read_to_string(input_path).expect("Should read input file content");
-
-    // Split by empty line to get
-    let lines: Vec<String> = input.clone().split('\n').map(|s| s.to_string()).collect();
-    lines
-}
-
+#[cfg(test)]
 mod test_utils {
     use super::*;
-    const INPUT: &str = "/home/user/dev/tutorial/adventOfCode/2022/day3/demo.txt";
+    use crate::DEMO as INPUT;
+    use aoc_common::Error;
 
     #[test]
     fn ok_read_lines() {
-        println!("{:?}", read_lines(INPUT));
+        println!("{:?}", INPUT.read_lines().unwrap());
     }
 
     #[test]
     fn get_compartments_ok() {
-        let lines = read_lines(INPUT);
-        let line = lines.get(0).unwrap();
+        let lines = INPUT.read_lines().unwrap();
+        let line = lines.first().unwrap();
         println!("{:?}", compartments(line));
     }
 
     #[test]
     fn get_shared_item_ok() {
-        let lines = read_lines(INPUT);
-        let line = lines.get(0).unwrap();
-        let shared = shared_item(line);
-        println!("{:?}", shared as u32);
+        let lines = INPUT.read_lines().unwrap();
+        let line = InputLine::new(DAY, 1, lines.first().unwrap());
+        let shared = shared_item(line).unwrap();
+        assert_eq!('p', char::from(shared));
     }
 
     #[test]
-    fn get_val_ok() {
-        assert_eq!(1, priority_val('a'));
-        assert_eq!(26, priority_val('z'));
-        assert_eq!(52, priority_val('Z'));
-        assert_eq!(27, priority_val('A'));
+    fn get_badges_sum_ok() {
+        assert_eq!(70, get_badges_sum(&INPUT).unwrap());
     }
 
     #[test]
-    fn get_badges_sum_ok() {
-        assert_eq!(70, get_badges_sum(INPUT));
+    fn get_total_ok() {
+        assert_eq!(157, total_priority_sum(&INPUT).unwrap());
     }
 
     #[test]
-    fn get_total_ok() {
-        assert_eq!(157, total_priority_sum(INPUT));
+    fn input_totals() {
+        assert_eq!(7674, total_priority_sum(&crate::INPUT).unwrap());
+        assert_eq!(2805, get_badges_sum(&crate::INPUT).unwrap());
+    }
+
+    #[test]
+    fn bad_rucksacks() {
+        let input = InputSource::embedded("abca\nab1b\n");
+        let Err(Error::Parse(err)) = total_priority_sum(&input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((2, 3, "1"), (err.line, err.column, err.text.as_str()));
+
+        let input = InputSource::embedded("abcd\n");
+        assert!(matches!(total_priority_sum(&input), Err(Error::Parse(_))));
+
+        let input = InputSource::embedded("abca\nacdc\n");
+        let Err(Error::Parse(err)) = get_badges_sum(&input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(1, err.line);
+    }
+
+    #[test]
+    fn priority_overflow() {
+        let line = InputLine::new(DAY, 7, "abca");
+        let a = Item::try_from(b'a').unwrap();
+        assert_eq!(u32::MAX, add_priority(u32::MAX - 1, a, line).unwrap());
+        let Err(Error::Parse(err)) = add_priority(u32::MAX, a, line) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(7, err.line);
     }
 }
 
-mod another_solution {
-
-    mod item {
-        // to represent item in rucksack i.e char => u8 with added gurantee to be char
-        #[repr(transparent)]
-        #[derive(Copy, Clone, PartialEq, Eq, Hash)]
-        pub(crate) struct Item(u8);
-
-        // convert from u8 to Item fallibly
-        impl TryFrom<u8> for Item {
-            type Error = color_eyre::Report;
-
-            fn try_from(value: u8) -> Result<Self, Self::Error> {
-                match value {
-                    b'a'..=b'z' | b'A'..=b'Z' => Ok(Item(value)),
-                    _ => Err(color_eyre::eyre::eyre!(
-                        "{} is not a valid item",
-                        value as char
-                    )),
-                }
-            }
-        }
+pub mod another_solution {
+    use im::HashSet as ImHashSet;
+    use itertools::Itertools;
+    use std::collections::HashSet;
 
-        impl std::fmt::Debug for Item {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "{}", self.0 as char)
-            }
-        }
+    use aoc_common::Error;
 
-        impl Item {
-            /// get an item's "priority",
-            pub(crate) fn score(self) -> usize {
-                match self {
-                    Item(b'a'..=b'z') => 1 + (self.0 - b'a') as usize,
-                    Item(b'A'..=b'Z') => 27 + (self.0 - b'A') as usize,
-                    _ => unreachable!(),
-                }
-            }
-        }
+    use crate::item::Item;
+
+    fn rucksack<S: FromIterator<Item>>(line: &str) -> aoc_common::Result<S> {
+        line.bytes().map(Item::try_from).collect()
     }
 
-    #[cfg(test)]
-    mod test_main {
-        use super::*;
-        use item::Item;
-        use itertools::Itertools;
+    /// sum of the priorities of the item in both compartments of each rucksack
+    pub fn part1(input: &str) -> aoc_common::Result<usize> {
+        let mut total_score = 0;
 
-        #[test]
-        fn check_part1() -> color_eyre::Result<()> {
-            let mut total_score = 0;
+        for line in input.lines() {
+            let (first, second) = line.split_at(line.len() / 2);
 
-            for line in include_str!("input.txt").lines() {
-                let (first, second) = line.split_at(line.len() / 2);
+            let first_items = first
+                .bytes()
+                .map(Item::try_from)
+                .collect::<Result<Vec<_>, _>>()?;
+            let second_items = second
+                .bytes()
+                .map(Item::try_from)
+                .collect::<Result<Vec<_>, _>>()?;
 
-                let first_items = first
-                    .bytes()
-                    .map(Item::try_from)
-                    .collect::<Result<Vec<_>, _>>()?;
+            let dupe = second_items
+                .into_iter()
+                .find(|item| first_items.contains(item))
+                .ok_or_else(|| Error::invalid(format!("no item in both halves of {line}")))?;
+            total_score += dupe.priority() as usize;
+        }
 
-                let dupe_score = second
-                    .bytes()
-                    .map(Item::try_from)
-                    .find_map(|item| {
-                        item.ok().and_then(|item| {
-                            first_items
-                                .iter()
-                                // the iterator gives us `&Item`, but we want `Item`, and
-                                // it's `Copy`, so we can call `copied()`
-                                .copied()
-                                // `find` gives us an `&Item`, but we want `Item`, so we
-                                // destructure the reference here:
-                                //    👇
-                                .find(|&first_item| first_item == item)
-                        })
-                    })
-                    .expect("there should be exactly one duplicate")
-                    .score();
-                dbg!(dupe_score);
-                total_score += dupe_score;
-            }
+        Ok(total_score)
+    }
 
-            dbg!(total_score);
-            Ok(())
-        }
+    /// badges of groups of 3, using a std `HashSet` per rucksack
+    pub fn part2_hashset(input: &str) -> aoc_common::Result<usize> {
+        let rucksacks = input
+            .lines()
+            .map(rucksack::<HashSet<Item>>)
+            .collect::<aoc_common::Result<Vec<_>>>()?;
+        check_groups(rucksacks.len())?;
+
+        rucksacks
+            .iter()
+            .tuples()
+            .enumerate()
+            .map(|(group, (a, b, c))| {
+                a.iter()
+                    .copied()
+                    .find(|i| b.contains(i) && c.contains(i))
+                    .map(|item| item.priority() as usize)
+                    .ok_or_else(|| no_badge(group))
+            })
+            .sum()
+    }
 
-        #[test]
-        fn check_part2() -> color_eyre::Result<()> {
-            // use im::HashSet;
-            use itertools::Itertools;
-            use std::collections::HashSet;
+    /// badges of groups of 3, intersecting persistent `im::HashSet`s
+    pub fn part2_im_hashset(input: &str) -> aoc_common::Result<usize> {
+        let rucksacks = input
+            .lines()
+            .map(rucksack::<ImHashSet<Item>>)
+            .collect::<aoc_common::Result<Vec<_>>>()?;
+        check_groups(rucksacks.len())?;
+
+        rucksacks
+            .into_iter()
+            .chunks(3)
+            .into_iter()
+            .enumerate()
+            .map(|(group, chunks)| {
+                chunks
+                    .reduce(|a, b| a.intersection(b))
+                    .and_then(|common| common.iter().next().copied())
+                    .map(|item| item.priority() as usize)
+                    .ok_or_else(|| no_badge(group))
+            })
+            .sum()
+    }
 
-            let rucksacks = include_str!("input.txt").lines().map(|line| {
+    /// badges of groups of 3, counting items in a `[u8; 53]` indexed by priority
+    pub fn part2_u8_array(input: &str) -> aoc_common::Result<usize> {
+        let rucksacks = input
+            .lines()
+            .map(|line| {
                 line.bytes()
                     .map(Item::try_from)
-                    .collect::<Result<HashSet<_>, _>>()
-            });
-
-            let sum = itertools::process_results(rucksacks, |rs| {
-                rs.tuples()
-                    .map(|(a, b, c)| {
-                        a.iter()
-                            .copied()
-                            .find(|i| b.contains(i) && c.contains(i))
-                            .map(|i| dbg!(i.score()))
-                            .unwrap_or_default()
+                    .try_fold([0u8; 53], |mut acc, x| {
+                        // scores are 1..=52, so this can't go out of bounds
+                        acc[x?.priority() as usize] = 1;
+                        Ok::<_, aoc_common::Error>(acc)
                     })
-                    .sum::<usize>()
-            })?;
-            dbg!(sum);
+            })
+            .collect::<aoc_common::Result<Vec<_>>>()?;
+        check_groups(rucksacks.len())?;
+
+        rucksacks
+            .into_iter()
+            .chunks(3)
+            .into_iter()
+            .enumerate()
+            .map(|(group, chunks)| {
+                chunks
+                    .reduce(|mut a, b| {
+                        // another trick: we're re-using `a` as the output array
+                        for (a, b) in a.iter_mut().zip(b.iter()) {
+                            *a += *b;
+                        }
+                        a
+                    })
+                    .and_then(|counts| counts.iter().position(|&b| b == 3))
+                    .ok_or_else(|| no_badge(group))
+            })
+            .sum()
+    }
+
+    // the last group can't be short of rucksacks
+    fn check_groups(rucksacks: usize) -> aoc_common::Result<()> {
+        match rucksacks % 3 {
+            0 => Ok(()),
+            left => Err(Error::invalid(format!(
+                "group #{}: only {left} of 3 rucksacks",
+                rucksacks / 3 + 1
+            ))),
+        }
+    }
+
+    // groups counting from 0
+    fn no_badge(group: usize) -> Error {
+        Error::invalid(format!("no badge shared by group #{}", group + 1))
+    }
+
+    #[cfg(test)]
+    mod test_main {
+        use super::*;
 
+        const INPUT: &str = include_str!("input.txt");
+
+        #[test]
+        fn check_part1() -> aoc_common::Result<()> {
+            assert_eq!(7674, part1(INPUT)?);
             Ok(())
         }
 
         #[test]
-        fn check_part2_chunks() -> color_eyre::Result<()> {
-            use im::HashSet;
-
-            let sum: usize = include_str!("input.txt")
-                .lines()
-                .map(|line| {
-                    line.bytes()
-                        .map(|b| b.try_into().unwrap())
-                        .collect::<HashSet<Item>>()
-                })
-                .chunks(3)
-                .into_iter()
-                .map(|chunks| {
-                    chunks
-                        .reduce(|a, b| a.intersection(b))
-                        .expect("we always have 3 chunks")
-                        .iter()
-                        .next()
-                        .expect("problem statement says there is always one item in common")
-                        .score()
-                })
-                .sum();
-            dbg!(sum);
+        fn bad_input() {
+            // not an item in the second half, no item in both halves
+            assert!(part1("abcd1a").is_err());
+            assert!(part1("abcd").is_err());
+            for part2 in [part2_hashset, part2_im_hashset, part2_u8_array] {
+                assert!(part2("ab\nbc\nca").is_err());
+                // a trailing partial group
+                assert!(part2("abc\nade\nafg\nxy\nxz").is_err());
+            }
+        }
 
+        #[test]
+        fn check_part2() -> aoc_common::Result<()> {
+            assert_eq!(2805, part2_hashset(INPUT)?);
             Ok(())
         }
 
         #[test]
-        fn check_part2_u8_array() {
-            use itertools::Itertools;
-            let sum: usize = include_str!("input.txt")
-                .lines()
-                .map(|line| {
-                    line.bytes().map(|b| b.try_into().unwrap()).fold(
-                        [0u8; 53],
-                        |mut acc, x: Item| {
-                            // this might panic!
-                            acc[x.score()] = 1;
-                            acc
-                        },
-                    )
-                })
-                .chunks(3)
-                .into_iter()
-                .map(|chunks| {
-                    chunks
-                        .reduce(|mut a, b| {
-                            // another trick: we're re-using `a` as the output array
-                            for (a, b) in a.iter_mut().zip(b.iter()) {
-                                *a += *b;
-                            }
-                            a
-                        })
-                        .expect("we always have 3 chunks")
-                        .iter()
-                        .position(|&b| b == 3)
-                        .expect("problem statement says there is always one item in common")
-                })
-                .sum();
-            dbg!(sum);
+        fn check_part2_chunks() -> aoc_common::Result<()> {
+            assert_eq!(2805, part2_im_hashset(INPUT)?);
+            Ok(())
+        }
+
+        #[test]
+        fn check_part2_u8_array() -> aoc_common::Result<()> {
+            assert_eq!(2805, part2_u8_array(INPUT)?);
+            Ok(())
         }
     }
 }
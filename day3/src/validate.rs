@@ -0,0 +1,225 @@
+//! Everything wrong with each rucksack, rather than the first error.
+
+use std::fmt;
+
+use aoc_common::{numbered_lines, InputLine, InputSource, Result};
+
+use crate::item::{Item, ItemSet};
+use crate::DAY;
+
+/// Something off about a rucksack
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// the compartments can't hold as many items each
+    OddLength(usize),
+    /// column from 1, in chars
+    NotAnItem { column: usize, found: char },
+    /// no item in both compartments
+    NothingShared,
+    /// more than one item in both compartments
+    SeveralShared(ItemSet),
+    /// items more than once in compartment 1 or 2, which the puzzle allows
+    Repeated { compartment: usize, items: ItemSet },
+}
+
+impl Problem {
+    /// `false` for what's only worth a note
+    pub fn is_error(&self) -> bool {
+        !matches!(self, Problem::Repeated { .. })
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::OddLength(len) => write!(f, "odd length, {len} items"),
+            Problem::NotAnItem { column, found } => {
+                write!(f, "not an item at column {column}: {found:?}")
+            }
+            Problem::NothingShared => write!(f, "no item in both compartments"),
+            Problem::SeveralShared(items) => {
+                write!(f, "{} items in both compartments, {items:?}", items.len())
+            }
+            Problem::Repeated { compartment, items } => {
+                write!(f, "repeated in compartment {compartment}: {items:?}")
+            }
+        }
+    }
+}
+
+/// The problems of a line, if any
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineReport {
+    /// from 1
+    pub line: usize,
+    pub problems: Vec<Problem>,
+}
+
+impl LineReport {
+    pub fn is_valid(&self) -> bool {
+        !self.problems.iter().any(Problem::is_error)
+    }
+}
+
+/// Every line of the input, checked
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub lines: Vec<LineReport>,
+}
+
+impl Report {
+    pub fn is_valid(&self) -> bool {
+        self.lines.iter().all(LineReport::is_valid)
+    }
+
+    /// the lines with errors, notes aside
+    pub fn invalid(&self) -> impl Iterator<Item = &LineReport> {
+        self.lines.iter().filter(|line| !line.is_valid())
+    }
+}
+
+/// the lines with problems, then how many are invalid
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.lines.iter().filter(|line| !line.problems.is_empty()) {
+            let problems: Vec<String> = line.problems.iter().map(Problem::to_string).collect();
+            writeln!(f, "line {}: {}", line.line, problems.join("; "))?;
+        }
+        write!(
+            f,
+            "{} of {} rucksacks invalid",
+            self.invalid().count(),
+            self.lines.len()
+        )
+    }
+}
+
+/// Checks every rucksack of the input, only failing if it can't be read
+pub fn validate(input: &InputSource) -> Result<Report> {
+    let input = input.read()?;
+    let lines = numbered_lines(DAY, &input)
+        .map(|line| LineReport {
+            line: line.number,
+            problems: problems(line),
+        })
+        .collect();
+    Ok(Report { lines })
+}
+
+/// what's off about the rucksack, in the order of [`Problem`]
+pub fn problems(line: InputLine) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let len = line.text.chars().count();
+    if !len.is_multiple_of(2) {
+        problems.push(Problem::OddLength(len));
+    }
+
+    let mut compartments = [Vec::new(), Vec::new()];
+    for (i, c) in line.text.chars().enumerate() {
+        match u8::try_from(c).ok().and_then(|b| Item::try_from(b).ok()) {
+            Some(item) => compartments[usize::from(i >= len / 2)].push(item),
+            None => problems.push(Problem::NotAnItem {
+                column: i + 1,
+                found: c,
+            }),
+        }
+    }
+
+    let [first, second] = compartments.map(|items| {
+        let mut seen = ItemSet::EMPTY;
+        let mut repeated = ItemSet::EMPTY;
+        for item in items {
+            if !seen.insert(item) {
+                repeated.insert(item);
+            }
+        }
+        (seen, repeated)
+    });
+    match first.0.intersection(second.0) {
+        shared if shared.is_empty() => problems.push(Problem::NothingShared),
+        shared if shared.len() > 1 => problems.push(Problem::SeveralShared(shared)),
+        _ => {}
+    }
+    for (compartment, (_, items)) in [(1, first), (2, second)] {
+        if !items.is_empty() {
+            problems.push(Problem::Repeated { compartment, items });
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod test_validate {
+    use super::*;
+    use crate::{DEMO, INPUT};
+
+    fn set(items: &str) -> ItemSet {
+        items.bytes().map(|b| Item::try_from(b).unwrap()).collect()
+    }
+
+    #[test]
+    fn valid_ok() {
+        for input in [DEMO, INPUT] {
+            let report = validate(&input).unwrap();
+            assert!(report.is_valid(), "{report}");
+        }
+
+        // vJrwpWtwJgWr|hcsFMMfFFhFp
+        let report = validate(&DEMO).unwrap();
+        assert_eq!(
+            vec![
+                Problem::Repeated {
+                    compartment: 1,
+                    items: set("wJWr")
+                },
+                Problem::Repeated {
+                    compartment: 2,
+                    items: set("hFM")
+                },
+            ],
+            report.lines[0].problems
+        );
+    }
+
+    #[test]
+    fn problems_ok() {
+        let line = |text| InputLine::new(DAY, 1, text);
+        assert_eq!(
+            vec![
+                Problem::OddLength(5),
+                Problem::NotAnItem {
+                    column: 3,
+                    found: '1'
+                },
+                Problem::SeveralShared(set("ab")),
+            ],
+            problems(line("ab1ab"))
+        );
+        assert_eq!(
+            vec![
+                Problem::NothingShared,
+                Problem::Repeated {
+                    compartment: 1,
+                    items: set("a")
+                },
+            ],
+            problems(line("aabc"))
+        );
+        assert!(problems(line("abcb")).is_empty());
+    }
+
+    #[test]
+    fn report_ok() {
+        let report = validate(&InputSource::text("abcb\nab1ab\naabc")).unwrap();
+        assert!(!report.is_valid());
+        let invalid: Vec<usize> = report.invalid().map(|line| line.line).collect();
+        assert_eq!(vec![2, 3], invalid);
+        assert_eq!(
+            "line 2: odd length, 5 items; not an item at column 3: '1'; \
+             2 items in both compartments, {a, b}\n\
+             line 3: no item in both compartments; repeated in compartment 1: {a}\n\
+             2 of 3 rucksacks invalid",
+            report.to_string()
+        );
+    }
+}
@@ -0,0 +1,40 @@
+//! Seeded synthetic inputs, for stress testing the solvers.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// `size` pairs of section assignments like "2-4,6-8", sections 1 to 99
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let mut area = || {
+            let start: u32 = rng.gen_range(1..=99);
+            let end: u32 = rng.gen_range(start..=99);
+            format!("{start}-{end}")
+        };
+        let (first, second) = (area(), area());
+        input.push_str(&format!("{first},{second}\n"));
+    }
+    input
+}
+
+#[cfg(test)]
+mod test_gen {
+    use super::*;
+    use crate::{get_containing_pairs, get_overlap_pairs};
+    use aoc_common::InputSource;
+
+    #[test]
+    fn generate_ok() {
+        let input = generate(7, 500);
+        assert_eq!(input, generate(7, 500));
+
+        let input = InputSource::text(input);
+        let containing = get_containing_pairs(&input).unwrap();
+        let overlap = get_overlap_pairs(&input).unwrap();
+        // every containing pair overlaps too
+        assert!(containing <= overlap && overlap <= 500);
+    }
+}
@@ -0,0 +1,10 @@
+pub mod gen;
+pub mod stream;
+mod utils;
+pub use utils::*;
+
+use aoc_common::InputSource;
+
+pub const DAY: u8 = 4;
+pub const INPUT: InputSource = InputSource::embedded(include_str!("input.txt"));
+pub const DEMO: InputSource = InputSource::embedded(include_str!("../demo.txt"));
@@ -1,8 +1,7 @@
-mod utils;
+use day4::INPUT;
 
-const INPUT: &str = "/home/user/dev/tutorial/adventOfCode/2022/day4/src/input.txt";
-
-fn main() {
-    println!("contains: {}", utils::get_containing_pairs(INPUT).unwrap());
-    println!("overlap: {}", utils::get_overlap_pairs(INPUT).unwrap());
+fn main() -> aoc_common::Result<()> {
+    println!("contains: {}", day4::get_containing_pairs(&INPUT)?);
+    println!("overlap: {}", day4::get_overlap_pairs(&INPUT)?);
+    Ok(())
 }
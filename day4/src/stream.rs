@@ -0,0 +1,53 @@
+//! Solvers reading the input line by line, in constant memory.
+
+use std::io::BufRead;
+
+use aoc_common::{for_each_line, Result};
+
+use crate::utils::{parse_pair, Area};
+use crate::DAY;
+
+// number of pairs `matches`
+fn count_pairs(reader: impl BufRead, matches: fn(&Area, &Area) -> bool) -> Result<u32> {
+    let mut count = 0;
+    for_each_line(DAY, reader, |line| {
+        let (first, second) = parse_pair(line)?;
+        if matches(&first, &second) {
+            count += 1;
+        }
+        Ok(())
+    })?;
+    Ok(count)
+}
+
+pub fn get_containing_pairs(reader: impl BufRead) -> Result<u32> {
+    count_pairs(reader, Area::contains)
+}
+
+pub fn get_overlap_pairs(reader: impl BufRead) -> Result<u32> {
+    count_pairs(reader, Area::overlap)
+}
+
+#[cfg(test)]
+mod test_stream {
+    use super::*;
+    use crate::{gen, DEMO, INPUT};
+    use aoc_common::InputSource;
+
+    #[test]
+    fn stream_matches_utils() {
+        let generated = InputSource::text(gen::generate(3, 1000));
+        for input in [DEMO, INPUT, generated] {
+            let reader = input.reader().unwrap();
+            assert_eq!(
+                crate::get_containing_pairs(&input).unwrap(),
+                get_containing_pairs(reader).unwrap()
+            );
+            let reader = input.reader().unwrap();
+            assert_eq!(
+                crate::get_overlap_pairs(&input).unwrap(),
+                get_overlap_pairs(reader).unwrap()
+            );
+        }
+    }
+}
@@ -1,17 +1,17 @@
 #![allow(unused)]
 
-use std::{any::Any, fs};
+use aoc_common::{numbered_lines, InputLine, InputSource, Result};
 
-type AnyResult<T> = Result<T, anyhow::Error>;
+use crate::DAY;
 
 #[derive(Debug)]
-struct Area {
+pub(crate) struct Area {
     start: u32,
     end: u32,
 }
 
 impl Area {
-    fn contains(&self, other: &Self) -> bool {
+    pub(crate) fn contains(&self, other: &Self) -> bool {
         let l_start = self.start < other.start;
         let g_end = self.end > other.end;
 
@@ -25,7 +25,7 @@ impl Area {
         false
     }
 
-    fn overlap(&self, other: &Self) -> bool {
+    pub(crate) fn overlap(&self, other: &Self) -> bool {
         let p1 = self.start;
         let p2 = self.end;
 
@@ -39,7 +39,7 @@ impl Area {
     }
 }
 
-pub fn get_overlap_pairs(input: &str) -> AnyResult<u32> {
+pub fn get_overlap_pairs(input: &InputSource) -> Result<u32> {
     let areas = get_areas(input)?;
     let count: u32 = areas
         .into_iter()
@@ -48,7 +48,7 @@ pub fn get_overlap_pairs(input: &str) -> AnyResult<u32> {
     Ok(count)
 }
 
-pub fn get_containing_pairs(input: &str) -> AnyResult<u32> {
+pub fn get_containing_pairs(input: &InputSource) -> Result<u32> {
     let areas = get_areas(input)?;
     let count: u32 = areas
         .into_iter()
@@ -57,56 +57,66 @@ pub fn get_containing_pairs(input: &str) -> AnyResult<u32> {
     Ok(count)
 }
 
-fn get_areas(input: &str) -> AnyResult<Vec<(Area, Area)>> {
-    
+fn get_areas(input: &InputSource) -> Result<Vec<(Area, Area)>> {
     // Gives String [done at runtime => arg: string variable]
-    let input = fs::read_to_string(input)?;
+    let input = input.read()?;
 
     // Gives &'static str [done at compile time => req arg: String literal]
     // let input = include_str!("input.txt");
 
     let mut areas: Vec<(Area, Area)> = Vec::new();
 
-    for line in input.lines() {
-        let areas_str: Vec<&str> = line.split(',').collect();
-        let first: Vec<&str> = areas_str[0].split('-').collect();
-        let second: Vec<&str> = areas_str[1].split('-').collect();
+    for line in numbered_lines(DAY, &input) {
+        areas.push(parse_pair(line)?);
+    }
 
-        let first_area = {
-            let start = first[0].parse::<u32>()?;
-            let end = first[1].parse::<u32>()?;
-            Area { start, end }
-        };
+    Ok(areas)
+}
 
-        let second_area = {
-            let start = second[0].parse::<u32>()?;
-            let end = second[1].parse::<u32>()?;
-            Area { start, end }
-        };
+// a pair like "2-4,6-8"
+pub(crate) fn parse_pair(line: InputLine) -> Result<(Area, Area)> {
+    let Some((first, second)) = line.text.split_once(',') else {
+        return Err(line.error("expected two areas separated by ','"));
+    };
 
-        let pair = (first_area, second_area);
-        areas.push(pair);
-    }
+    Ok((parse_area(line, first)?, parse_area(line, second)?))
+}
 
-    Ok(areas)
+// an area like "2-4"
+fn parse_area(line: InputLine, area: &str) -> Result<Area> {
+    let Some((start, end)) = area.split_once('-') else {
+        return Err(line.error_at(area, "expected an area like 2-4"));
+    };
+    let section = |id: &str| {
+        id.parse::<u32>()
+            .map_err(|e| line.error_at(id, format!("not a section id ({e})")))
+    };
+
+    let (start, end) = (section(start)?, section(end)?);
+    if start > end {
+        return Err(line.error_at(area, "the area ends before it starts"));
+    }
+    Ok(Area { start, end })
 }
 
 #[cfg(test)]
 mod test_utils {
     use super::*;
 
-    const INPUT: &str = "/home/user/dev/tutorial/adventOfCode/2022/day4/demo.txt";
+    use crate::DEMO as INPUT;
+    use aoc_common::Error;
+    use proptest::prelude::*;
 
     #[test]
     fn test_get_areas() {
-        let areas = get_areas(INPUT).unwrap();
+        let areas = get_areas(&INPUT).unwrap();
         let area = &areas[2];
         println!("{:?}", area);
     }
 
     #[test]
     fn test_contains() {
-        let areas = get_areas(INPUT).unwrap();
+        let areas = get_areas(&INPUT).unwrap();
         let area = &areas[4];
         let first = &area.0;
         let second = &area.1;
@@ -115,13 +125,73 @@ mod test_utils {
 
     #[test]
     fn get_count_containing_pairs_ok() {
-        let c = get_containing_pairs(INPUT);
+        let c = get_containing_pairs(&INPUT);
         assert_eq!(2, c.unwrap());
     }
 
     #[test]
     fn get_overlap_pairs_ok() {
-        let c = get_overlap_pairs(INPUT);
+        let c = get_overlap_pairs(&INPUT);
         assert_eq!(4, c.unwrap());
     }
+
+    // either way round, so inverted too
+    fn sections() -> impl Strategy<Value = (u32, u32)> {
+        (1..100u32, 1..100u32)
+    }
+
+    // the areas that parse
+    fn area() -> impl Strategy<Value = Area> {
+        sections().prop_filter_map("inverted area", |(start, end)| {
+            let text = format!("{start}-{end}");
+            parse_area(InputLine::new(DAY, 1, &text), &text).ok()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn parse_area_in_order((start, end) in sections()) {
+            let text = format!("{start}-{end}");
+            let area = parse_area(InputLine::new(DAY, 1, &text), &text);
+            prop_assert_eq!(start <= end, area.is_ok());
+        }
+
+
+        // cross-check against the plain interval comparisons
+        #[test]
+        fn contains_either_way(first in area(), second in area()) {
+            let expected = (first.start <= second.start && second.end <= first.end)
+                || (second.start <= first.start && first.end <= second.end);
+            prop_assert_eq!(expected, first.contains(&second));
+            prop_assert_eq!(expected, second.contains(&first));
+        }
+
+        #[test]
+        fn overlap_either_way(first in area(), second in area()) {
+            let expected = first.start <= second.end && second.start <= first.end;
+            prop_assert_eq!(expected, first.overlap(&second));
+            prop_assert_eq!(expected, second.overlap(&first));
+        }
+    }
+
+    #[test]
+    fn bad_areas() {
+        let input = InputSource::embedded("2-4,6-8\n2-4,6-x\n");
+        let Err(Error::Parse(err)) = get_areas(&input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((2, 7, "x"), (err.line, err.column, err.text.as_str()));
+
+        let input = InputSource::embedded("2-4 6-8\n");
+        let Err(Error::Parse(err)) = get_areas(&input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((1, 1, "2-4 6-8"), (err.line, err.column, err.text.as_str()));
+
+        let input = InputSource::embedded("2-4,8-6\n");
+        let Err(Error::Parse(err)) = get_areas(&input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((1, 5, "8-6"), (err.line, err.column, err.text.as_str()));
+    }
 }
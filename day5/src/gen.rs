@@ -0,0 +1,91 @@
+//! Seeded synthetic inputs, for stress testing the solvers.
+
+use rand::rngs::StdRng;
+use rand::seq::IteratorRandom;
+use rand::{Rng, SeedableRng};
+
+const STACKS: usize = 9;
+const MAX_HEIGHT: usize = 8;
+
+/// A crate arrangement of 9 stacks followed by `size` moves, each of them
+/// legal when the moves are done in order
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut stacks: Vec<Vec<char>> = (0..STACKS)
+        .map(|_| {
+            let height = rng.gen_range(0..=MAX_HEIGHT);
+            (0..height).map(|_| rng.gen_range('A'..='Z')).collect()
+        })
+        .collect();
+    // at least one crate, or nothing could ever move
+    if stacks.iter().all(Vec::is_empty) {
+        stacks[0].push(rng.gen_range('A'..='Z'));
+    }
+
+    let mut input = arrangement(&stacks);
+    input.push('\n');
+
+    // only the heights matter from here on
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    for _ in 0..size {
+        let from = (0..STACKS)
+            .filter(|&i| heights[i] > 0)
+            .choose(&mut rng)
+            .unwrap();
+        let to = (0..STACKS).filter(|&i| i != from).choose(&mut rng).unwrap();
+        let number = rng.gen_range(1..=heights[from]);
+        heights[from] -= number;
+        heights[to] += number;
+
+        input.push_str(&format!("move {number} from {} to {}\n", from + 1, to + 1));
+    }
+    input
+}
+
+// drawn top down like the puzzle, bottom crates first in each stack
+fn arrangement(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or_default();
+    let mut lines = Vec::new();
+
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(item) => format!("[{item}]"),
+                None => "   ".to_string(),
+            })
+            .collect();
+        lines.push(row.join(" "));
+    }
+    let labels: Vec<String> = (1..=stacks.len())
+        .map(|label| format!(" {label} "))
+        .collect();
+    lines.push(labels.join(" "));
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod test_gen {
+    use super::*;
+    use crate::read_input;
+    use crate::stack::Stacks;
+    use aoc_common::InputSource;
+
+    #[test]
+    fn generate_ok() {
+        let input = generate(7, 300);
+        assert_eq!(input, generate(7, 300));
+
+        let (setup_str, moves) = read_input(&InputSource::text(input)).unwrap();
+        assert_eq!(300, moves.len());
+
+        let mut stacks = Stacks::from_arrangement(setup_str.clone()).unwrap();
+        let mut stacks_2 = Stacks::from_arrangement(setup_str).unwrap();
+        for todo in moves.iter() {
+            stacks.do_move_single_crate(todo).unwrap();
+            stacks_2.do_move_multiple_crates(todo).unwrap();
+        }
+    }
+}
@@ -1,18 +1,24 @@
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use aoc_common::InputLine;
+
+use crate::DAY;
 
 #[repr(transparent)]
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub struct Item(u8);
 
 impl TryFrom<u8> for Item {
-    type Error = color_eyre::Report;
+    type Error = aoc_common::Error;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
             b'A'..=b'Z' => Ok(Item(value)),
-            _ => Err(color_eyre::eyre::eyre!(
+            _ => Err(aoc_common::Error::invalid(format!(
                 "{} is not a valid item",
                 value as char
-            )),
+            ))),
         }
     }
 }
@@ -22,21 +28,14 @@ impl Item {
         self.0 as char 
     }
 
-    pub fn get_crate(item_str: &str) -> Result<Item, color_eyre::Report> {
-        let item_str = item_str
-            .split(' ')
-            .filter(|s| !s.is_empty())
-            .collect::<Vec<&str>>();
-
-        let item = item_str[0];
+    pub fn get_crate(item_str: &str) -> Result<Item, aoc_common::Error> {
+        let item = item_str.trim();
 
         if item.chars().count() != 3 {
-            return Err(color_eyre::eyre::eyre!("Item should have format: '[A]'"));
+            return Err(aoc_common::Error::invalid("Item should have format: '[A]'"));
         }
 
-        let mut chars = item.chars();
-        let _ch = chars.next();
-        let ch = chars.next().expect("Able to get char from item string") as u8;
+        let ch = item.chars().nth(1).and_then(|c| u8::try_from(c).ok()).unwrap_or_default();
 
         let item = Item::try_from(ch)?;
         Ok(item)
@@ -54,43 +53,57 @@ impl std::fmt::Debug for Item {
 pub struct Move{
     pub number: u32,
     pub from_stack: usize,
-    pub to_stack: usize
+    pub to_stack: usize,
+    /// the line it was read from, 1-based
+    pub line: usize
 }
 
 impl Move{
-    fn create(number: u32, from_stack: usize, to_stack: usize) -> Move{
-        Move { number, from_stack, to_stack }
+    fn create(number: u32, from_stack: usize, to_stack: usize, line: usize) -> Move{
+        Move { number, from_stack, to_stack, line }
     }
 
-    pub fn read_moves(moves_str: Vec<String>) -> Vec<Move>{
-        let mut all_moves = Vec::new();
-
-        for move_str in moves_str.into_iter() {
-            let this_move: Vec<u32> = move_str.split(' ').filter_map(|x| x.parse::<u32>().ok()).collect();
-            let number = this_move[0];
-            let from_stack = this_move[1] as usize;
-            let to_stack = this_move[2] as usize;
-
-            let this_move = Move::create(number, from_stack, to_stack);
+    /// parse a line like "move 1 from 2 to 1"
+    pub fn parse(line: InputLine) -> Result<Move, aoc_common::Error> {
+        let tokens: Vec<&str> = line.text.split(' ').collect();
+        let ["move", number, "from", from_stack, "to", to_stack] = tokens[..] else {
+            return Err(line.error("expected a move like 'move 1 from 2 to 1'"));
+        };
+
+        let number = parse_number(line, number)?;
+        let from_stack = parse_number(line, from_stack)?;
+        let to_stack = parse_number(line, to_stack)?;
+        Ok(Move::create(number, from_stack, to_stack, line.number))
+    }
 
-            all_moves.push(this_move);
-        }
+    /// an error at the move's line, which reads back as the move
+    pub fn error(&self, reason: impl Into<String>) -> aoc_common::Error {
+        let text = self.to_string();
+        InputLine::new(DAY, self.line, &text).error(reason)
+    }
+}
 
-        // dbg!(all_moves.clone());
-        all_moves
+impl std::fmt::Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "move {} from {} to {}", self.number, self.from_stack, self.to_stack)
     }
 }
 
+fn parse_number<T: FromStr<Err = ParseIntError>>(line: InputLine, token: &str) -> Result<T, aoc_common::Error> {
+    token
+        .parse::<T>()
+        .map_err(|e| line.error_at(token, format!("not a number ({e})")))
+}
+
 
 #[cfg(test)]
 mod test_item {
     use super::*;
-    use crate::read_input;
-    const INPUT: &str = "/home/user/dev/tutorial/adventOfCode/2022/day5/src/input.txt";
+    use crate::{read_input, INPUT};
 
     #[test]
     fn test_reading_moves() {
-        let (setup_str, moves_str) = read_input(INPUT).unwrap();
-        dbg!(Move::read_moves(moves_str));
+        let (setup_str, moves) = read_input(&INPUT).unwrap();
+        dbg!(moves);
     }
 }
\ No newline at end of file
@@ -1,44 +1,62 @@
 #![allow(unused)]
 
-mod item;
-mod stack;
+pub mod gen;
+pub mod item;
+pub mod stack;
 
 use item::*;
 use stack::*;
 
-pub fn read_input(file_path: &str) -> Result<(Vec<String>, Vec<String>), color_eyre::Report> {
-    use std::fs;
-    if let Ok(input) = fs::read_to_string(file_path) {
-        let input: Vec<String> = input.split("\n\n").map(|s| s.to_string()).collect();
-
-        let arrangement: Vec<String> = input[0]
-            .clone()
-            .split('\n')
-            .map(|s| s.to_string())
-            .collect();
-
-        let moves: Vec<String> = input[1]
-            .clone()
-            .split('\n')
-            .map(|s| s.to_string())
-            .collect();
-
-        Ok((arrangement, moves))
-    } else {
-        Err(color_eyre::eyre::eyre!("Error reading the input file"))
+use aoc_common::InputSource;
+
+pub const DAY: u8 = 5;
+pub const INPUT: InputSource = InputSource::embedded(include_str!("input.txt"));
+pub const DEMO: InputSource = InputSource::embedded(include_str!("demo.txt"));
+
+pub fn read_input(input: &InputSource) -> aoc_common::Result<(Vec<String>, Vec<Move>)> {
+    let input = input.read()?;
+    let mut paragraphs = aoc_common::paragraphs(DAY, &input).into_iter();
+
+    // the crate arrangement, then a blank line, then the moves; the
+    // arrangement has to start the input, its rows are numbered from there
+    let arrangement: Vec<String> = match paragraphs.next() {
+        Some(rows) if rows[0].number == 1 => rows.iter().map(|row| row.text.to_string()).collect(),
+        _ => Vec::new(),
+    };
+    let moves = paragraphs
+        .flatten()
+        .map(Move::parse)
+        .collect::<aoc_common::Result<Vec<Move>>>()?;
+
+    if arrangement.is_empty() || moves.is_empty() {
+        return Err(aoc_common::Error::invalid(
+            "expected the crate arrangement and the moves, separated by a blank line",
+        ));
     }
+    Ok((arrangement, moves))
 }
 
 #[cfg(test)]
 mod test_lib {
     use super::*;
-    const INPUT: &str = "/home/user/dev/tutorial/adventOfCode/2022/day5/src/input.txt";
 
     #[test]
     fn read_items_ok() {
-        let (setup_str, moves_str) = read_input(INPUT).unwrap();
-        dbg!(moves_str);
+        let (setup_str, moves) = read_input(&INPUT).unwrap();
+        assert_eq!(setup_str.len(), 9);
+        assert_eq!(moves.len(), 504);
     }
 
-    
+    #[test]
+    fn bad_move() {
+        let input = InputSource::embedded("[A]\n 1 \n\nmove 1 from 1 to 1\nmove x from 1 to 2\n");
+        let Err(aoc_common::Error::Parse(err)) = read_input(&input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((5, 6, "x"), (err.line, err.column, err.text.as_str()));
+
+        // the arrangement comes first
+        let input = InputSource::embedded("\n[A]\n 1 \n\nmove 1 from 1 to 1\n");
+        assert!(read_input(&input).is_err());
+    }
 }
@@ -2,7 +2,10 @@
 
 use itertools::Itertools;
 
+use aoc_common::InputLine;
+
 use crate::item::*;
+use crate::DAY;
 
 #[derive(Debug, Clone)]
 pub struct Stack(Vec<Item>);
@@ -12,13 +15,13 @@ impl Stack {
         self.0.pop()
     }
 
-    fn push_str(&mut self, item_str: &str) -> Result<(), color_eyre::Report> {
+    fn push_str(&mut self, item_str: &str) -> Result<(), aoc_common::Error> {
         let item = Item::get_crate(item_str)?;
         self.0.push(item);
         Ok(())
     }
 
-    fn push(&mut self, item: Item) -> Result<(), color_eyre::Report> {
+    fn push(&mut self, item: Item) -> Result<(), aoc_common::Error> {
         self.0.push(item);
         Ok(())
     }
@@ -36,86 +39,121 @@ impl Stack {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Stacks {
     stacks: Vec<Stack>,
 }
 
 impl Stacks {
     pub fn new() -> Stacks {
-        Stacks { stacks: Vec::new() }
+        Stacks::default()
     }
 
     pub fn len(&self) -> usize {
         self.stacks.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.stacks.is_empty()
+    }
+
     pub fn add_stack(&mut self, stack: Stack) {
         self.stacks.push(stack);
     }
 
-    pub fn from_arrangement(setup_str: Vec<String>) -> Result<Stacks, color_eyre::Report> {
+    pub fn from_arrangement(setup_str: Vec<String>) -> Result<Stacks, aoc_common::Error> {
         let mut stacks = Stacks::new();
-        let num_crates = setup_str.len();
+        // the last line numbers the stacks: " 1   2   3 "
+        let num_crates = setup_str
+            .last()
+            .map(|labels| labels.split_whitespace().count())
+            .unwrap_or_default();
 
         stacks.stacks = vec![Stack::new(); num_crates];
 
-        for line in setup_str.into_iter().rev().skip(1) {
-            let item_opt: Vec<Option<Item>> = line
-                .chars()
-                .tuples()
-                .map(|(a, b, c, d)| match (a, b, c, d) {
-                    ('[', word @ 'A'..='Z', ']', _) => Some(Item::try_from(word as u8).unwrap()),
-                    (' ', ' ', ' ', ' ') => None,
-                    _ => None,
-                })
-                .collect();
-
-            for (pos, item) in item_opt.into_iter().enumerate() {
-                if let Some(item) = item {
-                    stacks.stacks[pos].push(item);
-                }
+        // the arrangement starts the input, so line numbers are 1-based indices
+        for (index, text) in setup_str.iter().enumerate().rev().skip(1) {
+            let line = InputLine::new(DAY, index + 1, text);
+
+            // every crate takes 4 columns: "[X] "
+            for (pos, start) in (0..text.len()).step_by(4).enumerate() {
+                let chunk = text
+                    .get(start..text.len().min(start + 3))
+                    .ok_or_else(|| line.error("expected crates like [A]"))?;
+
+                let item = match chunk.as_bytes() {
+                    [b'[', word @ b'A'..=b'Z', b']'] => Item::try_from(*word)?,
+                    _ if chunk.trim().is_empty() => continue,
+                    _ => return Err(line.error_at(chunk, "expected a crate like [A]")),
+                };
+                let Some(stack) = stacks.stacks.get_mut(pos) else {
+                    return Err(line.error_at(chunk, format!("there are only {num_crates} stacks")));
+                };
+                stack.push(item)?;
             }
         }
         Ok(stacks)
     }
 
-    pub fn do_move_single_crate(&mut self, todo: &Move) {
+    // stacks are numbered from 1
+    fn check_move(&self, todo: &Move) -> Result<(), aoc_common::Error> {
+        for stack in [todo.from_stack, todo.to_stack] {
+            if stack == 0 || stack > self.len() {
+                return Err(todo.error(format!("there is no stack {stack}")));
+            }
+        }
+
+        if todo.from_stack == todo.to_stack {
+            return Err(todo.error("crates can't move onto the stack they're on"));
+        }
+
+        let available = self.stacks[todo.from_stack - 1].len();
+        if available < todo.number as usize {
+            return Err(todo.error(format!(
+                "stack {} only has {available} crates",
+                todo.from_stack
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn do_move_single_crate(&mut self, todo: &Move) -> Result<(), aoc_common::Error> {
+        self.check_move(todo)?;
         for i in 1..=todo.number {
             let popped = self.stacks[todo.from_stack - 1]
                 .pop()
-                .expect("None can't be popped");
-            self.stacks[todo.to_stack - 1].push(popped);
+                .expect("checked there are enough crates");
+            self.stacks[todo.to_stack - 1].push(popped)?;
         }
+        Ok(())
     }
 
-    pub fn do_move_multiple_crates(&mut self, todo: &Move) {
+    pub fn do_move_multiple_crates(&mut self, todo: &Move) -> Result<(), aoc_common::Error> {
+        self.check_move(todo)?;
         let from_len = self.stacks[todo.from_stack - 1].len();
-        let mid = from_len
-            .checked_sub(todo.number as usize)
-            .expect("Overflow occured during substraction");
+        let mid = from_len - todo.number as usize;
 
         let mut from_stack = &mut self.stacks[todo.from_stack - 1].clone();
         let mut to_stack = &mut self.stacks[todo.to_stack - 1].clone();
         
         for item in from_stack.0.drain(mid..from_len) {
-            to_stack.push(item);
+            to_stack.push(item)?;
         }
 
         // dbg!(&to_stack);
         self.stacks[todo.from_stack - 1] = from_stack.to_owned();
         self.stacks[todo.to_stack - 1] = to_stack.to_owned();
+        Ok(())
     }
 
     pub fn get_stack_tops(&self) -> Vec<Item> {
-        let mut tops = Vec::new();
-        let mut top_str = "".to_string();
-        for stack in self.stacks.iter() {
-            tops.push(stack.last().unwrap().to_owned());
-            top_str.push(tops.last().unwrap().get_char());
-        }
-        dbg!(top_str);
-        tops
+        // an empty stack has no top
+        self.stacks.iter().filter_map(Stack::last).copied().collect()
+    }
+
+    /// the crates on top of the stacks, as the puzzle wants them typed in
+    pub fn top_str(&self) -> String {
+        self.get_stack_tops().iter().map(Item::get_char).collect()
     }
 }
 
@@ -126,32 +164,30 @@ trait StackTrait {
 #[cfg(test)]
 mod test_stacks {
     use super::*;
-    const INPUT: &str = "/home/user/dev/tutorial/adventOfCode/2022/day5/src/input.txt";
-    use crate::read_input;
+    use crate::{read_input, INPUT};
 
     #[test]
     fn get_setup_from_input() {
-        let (setup_str, moves_str) = read_input(INPUT).unwrap();
+        let (setup_str, moves_str) = read_input(&INPUT).unwrap();
         dbg!(setup_str);
     }
 
     #[test]
     fn get_moves_from_input() {
-        let (setup_str, moves_str) = read_input(INPUT).unwrap();
+        let (setup_str, moves_str) = read_input(&INPUT).unwrap();
         dbg!(moves_str);
     }
 
     #[test]
     fn get_stack_arrangement() {
-        let (setup_str, moves_str) = read_input(INPUT).unwrap();
+        let (setup_str, moves_str) = read_input(&INPUT).unwrap();
         dbg!(Stacks::from_arrangement(setup_str));
     }
 
     #[test]
     fn get_multiple_move_ok() {
-        let (setup_str, moves_str) = read_input(INPUT).unwrap();
+        let (setup_str, all_moves) = read_input(&INPUT).unwrap();
         let mut stacks = Stacks::from_arrangement(setup_str).unwrap();
-        let all_moves = Move::read_moves(moves_str);
         let todo = all_moves.get(3).unwrap();
         let from_stack = &stacks.stacks[todo.from_stack - 1];
         let to_stack = &stacks.stacks[todo.to_stack - 1];
@@ -160,10 +196,48 @@ mod test_stacks {
         dbg!(to_stack);
         dbg!(todo);
 
-        stacks.do_move_multiple_crates(todo);
+        stacks.do_move_multiple_crates(todo).unwrap();
         let from_stack = &stacks.stacks[todo.from_stack - 1];
         let to_stack = &stacks.stacks[todo.to_stack - 1];
         dbg!(from_stack);
         dbg!(to_stack);
     }
+
+    #[test]
+    fn bad_arrangement() {
+        let setup = vec!["[A] (B)".to_string(), " 1   2 ".to_string()];
+        let Err(aoc_common::Error::Parse(err)) = Stacks::from_arrangement(setup) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((1, 5, "(B)"), (err.line, err.column, err.text.as_str()));
+
+        let setup = vec!["[A] [B]".to_string(), " 1 ".to_string()];
+        assert!(Stacks::from_arrangement(setup).is_err());
+    }
+
+    #[test]
+    fn bad_move() {
+        let setup = vec!["[A]    ".to_string(), " 1   2 ".to_string()];
+        let mut stacks = Stacks::from_arrangement(setup).unwrap();
+        let todo = |number, from_stack, to_stack| Move {
+            number,
+            from_stack,
+            to_stack,
+            line: 12,
+        };
+
+        let Err(aoc_common::Error::Parse(err)) = stacks.do_move_single_crate(&todo(1, 3, 1)) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((12, "move 1 from 3 to 1"), (err.line, err.text.as_str()));
+        assert_eq!("there is no stack 3", err.reason);
+        assert!(stacks.do_move_single_crate(&todo(1, 2, 1)).is_err());
+        assert!(stacks.do_move_multiple_crates(&todo(2, 1, 2)).is_err());
+        // would duplicate the crates
+        assert!(stacks.do_move_multiple_crates(&todo(1, 1, 1)).is_err());
+        assert!(stacks.do_move_single_crate(&todo(1, 1, 1)).is_err());
+        assert_eq!(1, stacks.stacks[0].len());
+        stacks.do_move_multiple_crates(&todo(1, 1, 2)).unwrap();
+        assert_eq!('A', stacks.get_stack_tops()[0].get_char());
+    }
 }
@@ -0,0 +1,25 @@
+// cargo bench -p day6, report in target/criterion/report/index.html
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use day6::{find_marker, find_marker_v1, INPUT};
+
+fn markers(c: &mut Criterion) {
+    let input = INPUT.read().unwrap();
+    let line = input.lines().next().unwrap();
+
+    let mut group = c.benchmark_group("day6/markers");
+    for size in [4, 14] {
+        group.bench_with_input(
+            BenchmarkId::new("no_repeated_chars", size),
+            &size,
+            |b, &size| b.iter(|| find_marker_v1(black_box(line), size)),
+        );
+        group.bench_with_input(BenchmarkId::new("find_marker", size), &size, |b, &size| {
+            b.iter(|| find_marker(black_box(line), size))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, markers);
+criterion_main!(benches);
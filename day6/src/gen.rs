@@ -0,0 +1,58 @@
+//! Seeded synthetic inputs, for stress testing the solvers.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+const MARKER: usize = 14;
+
+/// A datastream of `size` chars (at least 15) with a planted message marker
+pub fn generate(seed: u64, size: usize) -> String {
+    planted(seed, size).0
+}
+
+// the datastream, and where the planted marker starts
+//
+// before the marker only 'a', 'b' and 'c' occur, so there are no 4 distinct
+// chars in a row. The prefix ends with 'a' and the marker starts with one, so
+// every window across the boundary holds 'a' twice and isn't a marker either.
+// Both the start of packet and the start of message marker then start where
+// the planted one does.
+fn planted(seed: u64, size: usize) -> (String, usize) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(MARKER + 1);
+    let start = rng.gen_range(1..=size - MARKER);
+
+    let mut stream: Vec<u8> = (1..start)
+        .map(|_| *b"abc".choose(&mut rng).unwrap())
+        .collect();
+    stream.push(b'a');
+
+    let mut marker: Vec<u8> = (b'b'..=b'z').collect();
+    marker.shuffle(&mut rng);
+    stream.push(b'a');
+    stream.extend(&marker[..MARKER - 1]);
+
+    while stream.len() < size {
+        stream.push(rng.gen_range(b'a'..=b'z'));
+    }
+    (String::from_utf8(stream).unwrap() + "\n", start)
+}
+
+#[cfg(test)]
+mod test_gen {
+    use super::*;
+    use crate::find_marker;
+
+    #[test]
+    fn generate_ok() {
+        for seed in 0..50 {
+            let (input, start) = planted(seed, 1000);
+            assert_eq!(input, generate(seed, 1000));
+            assert_eq!(1001, input.len());
+
+            assert_eq!(Some(start + 4), find_marker(&input, 4));
+            assert_eq!(Some(start + MARKER), find_marker(&input, MARKER));
+        }
+    }
+}
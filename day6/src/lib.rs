@@ -1,8 +1,15 @@
 #![allow(unused)]
 
+pub mod gen;
+pub mod stream;
+
 use std::collections::HashSet;
-use std::{error::Error, fs};
-pub const INPUT: &str = "/home/user/dev/tutorial/adventOfCode/2022/day6/src/demo.txt";
+
+use aoc_common::{numbered_lines, Error, InputSource, Result};
+
+pub const DAY: u8 = 6;
+pub const INPUT: InputSource = InputSource::embedded(include_str!("input.txt"));
+pub const DEMO: InputSource = InputSource::embedded(include_str!("demo.txt"));
 
 /// true if no no_repeated_chars in input
 fn no_repeated_chars(input: &str) -> bool {
@@ -18,40 +25,63 @@ fn no_repeated_chars(input: &str) -> bool {
     true
 }
 
-pub fn solution_v1() {
-    let result: Vec<usize> = include_str!("input.txt")
+/// start of message marker of each datastream, 0 if there is none
+pub fn solution_v1(input: &InputSource) -> Result<Vec<usize>> {
+    let result: Vec<usize> = input
+        .read()?
         .lines()
         .map(|line| {
             let jump: usize = 14; // flip to 4 for part 1, 14 for part2
-            let mut index: usize = 0;
-            let mut result = 0;
-
-            let input_chars = line.chars().collect::<Vec<char>>();
-            while index + jump < input_chars.len() {
-                let chunk: String = input_chars[index..(index + jump)].iter().collect();
-
-                if no_repeated_chars(&chunk) {
-                    result = index + jump;
-                    break;
-                }
-                index += 1;
-            }
-            // dbg!(&line, &result, &index);
-            result
+            find_marker_v1(line, jump).unwrap_or_default()
         })
         .collect();
 
-    dbg!(result);
+    Ok(result)
+}
+
+/// first take on `find_marker`: check every chunk with `no_repeated_chars`
+pub fn find_marker_v1(line: &str, jump: usize) -> Option<usize> {
+    let mut index: usize = 0;
+
+    let input_chars = line.chars().collect::<Vec<char>>();
+    while index + jump <= input_chars.len() {
+        let chunk: String = input_chars[index..(index + jump)].iter().collect();
+
+        if no_repeated_chars(&chunk) {
+            return Some(index + jump);
+        }
+        index += 1;
+    }
+    None
 }
 
 // need itertools
-fn find_marker(input: &str) -> Option<usize> {
+/// position right after the first `size` distinct chars in input, `None`
+/// for a `size` of 0
+pub fn find_marker(input: &str, size: usize) -> Option<usize> {
+    if size == 0 {
+        return None;
+    }
     input
         .as_bytes()
-        .windows(4)
-        .position(|window| window.iter().collect::<HashSet<_>>().len() == 4)
+        .windows(size)
+        .position(|window| window.iter().collect::<HashSet<_>>().len() == size)
         //         was 3 👇
-        .map(|pos| pos + 4)
+        .map(|pos| pos + size)
+}
+
+/// marker position for each datastream (line) of the file
+pub fn markers(input: &InputSource, size: usize) -> Result<Vec<usize>> {
+    if size == 0 {
+        return Err(Error::invalid("a marker is at least 1 char"));
+    }
+    let input = input.read()?;
+    numbered_lines(DAY, &input)
+        .map(|line| {
+            find_marker(line.text, size)
+                .ok_or_else(|| line.error(format!("no marker of {size} distinct chars")))
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -60,7 +90,7 @@ mod test_signals {
 
     #[test]
     fn test_signal_lock() {
-        solution_v1();
+        assert_eq!(vec![3444], solution_v1(&INPUT).unwrap());
     }
 
     use super::find_marker;
@@ -72,6 +102,52 @@ mod test_signals {
     #[test_case(10, "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")]
     #[test_case(11, "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")]
     fn test_find_marker(index: usize, input: &str) {
-        assert_eq!(Some(index), find_marker(input));
+        assert_eq!(Some(index), find_marker(input, 4));
+    }
+
+    #[test_case(19, "mjqjpqmgbljsphdztnvjfqwrcgsmlb")]
+    #[test_case(23, "bvwbjplbgvbhsrlpgdmjqwftvncz")]
+    #[test_case(23, "nppdvjthqldpwncqszvftbrmjlhg")]
+    #[test_case(29, "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")]
+    #[test_case(26, "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")]
+    fn test_find_message_marker(index: usize, input: &str) {
+        assert_eq!(Some(index), find_marker(input, 14));
+    }
+
+    #[test]
+    fn test_markers() {
+        assert_eq!(vec![7, 5, 6, 10, 11], markers(&DEMO, 4).unwrap());
+    }
+
+    #[test]
+    fn v1_marker_at_end() {
+        assert_eq!(Some(4), find_marker_v1("aabc", 3));
+        assert_eq!(Some(4), find_marker_v1("abcd", 4));
+    }
+
+    proptest::proptest! {
+        // short alphabet, so streams with and without a marker both show up
+        #[test]
+        fn v1_agrees_with_find_marker(stream in "[a-h]{0,40}", size in 1..=8usize) {
+            proptest::prop_assert_eq!(find_marker(&stream, size), find_marker_v1(&stream, size));
+        }
+    }
+
+    #[test]
+    fn zero_size() {
+        assert_eq!(None, find_marker("abcd", 0));
+        assert!(matches!(
+            markers(&DEMO, 0),
+            Err(aoc_common::Error::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn no_marker() {
+        let input = InputSource::embedded("abcd\naabb\n");
+        let Err(aoc_common::Error::Parse(err)) = markers(&input, 4) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((2, "aabb"), (err.line, err.text.as_str()));
     }
 }
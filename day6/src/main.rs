@@ -1,5 +1,6 @@
 use day6::*;
 
-fn main() {
-    solution_v1();
+fn main() -> aoc_common::Result<()> {
+    println!("{:?}", solution_v1(&INPUT)?);
+    Ok(())
 }
@@ -0,0 +1,122 @@
+//! Solvers reading the input byte by byte, in constant memory, so even a
+//! single huge datastream line never has to fit in RAM.
+
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+use aoc_common::{Error, InputLine, Result};
+
+use crate::DAY;
+
+// looks for `size` distinct bytes in a row, one byte at a time
+struct Scanner {
+    size: usize,
+    read: usize,
+    found: Option<usize>,
+    window: VecDeque<u8>,
+    counts: [usize; 256],
+    distinct: usize,
+}
+
+impl Scanner {
+    fn new(size: usize) -> Scanner {
+        Scanner {
+            size,
+            read: 0,
+            found: None,
+            window: VecDeque::with_capacity(size),
+            counts: [0; 256],
+            distinct: 0,
+        }
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.read += 1;
+        if self.found.is_some() {
+            return;
+        }
+
+        if self.window.len() == self.size {
+            let old = self.window.pop_front().unwrap();
+            self.counts[old as usize] -= 1;
+            if self.counts[old as usize] == 0 {
+                self.distinct -= 1;
+            }
+        }
+        self.window.push_back(byte);
+        self.counts[byte as usize] += 1;
+        if self.counts[byte as usize] == 1 {
+            self.distinct += 1;
+        }
+
+        if self.distinct == self.size {
+            self.found = Some(self.read);
+        }
+    }
+
+    // the marker of the line that just ended, ready for the next one
+    fn finish(&mut self, number: usize) -> Result<usize> {
+        let found = self.found;
+        *self = Scanner::new(self.size);
+        found.ok_or_else(|| {
+            InputLine::new(DAY, number, "")
+                .error(format!("no marker of {} distinct chars", self.size))
+        })
+    }
+}
+
+/// marker position for each datastream (line) read
+pub fn markers(reader: impl BufRead, size: usize) -> Result<Vec<usize>> {
+    if size == 0 {
+        return Err(Error::invalid("a marker is at least 1 char"));
+    }
+    let mut markers = Vec::new();
+    let mut scanner = Scanner::new(size);
+
+    for byte in reader.bytes() {
+        let byte = byte.map_err(|source| Error::Io {
+            input: format!("day {DAY}"),
+            source,
+        })?;
+        match byte {
+            b'\n' => markers.push(scanner.finish(markers.len() + 1)?),
+            // so "\r\n" ends a line like "\n" does
+            b'\r' => {}
+            _ => scanner.push(byte),
+        }
+    }
+    // the last line doesn't need a newline
+    if scanner.read > 0 {
+        markers.push(scanner.finish(markers.len() + 1)?);
+    }
+    Ok(markers)
+}
+
+#[cfg(test)]
+mod test_stream {
+    use super::*;
+    use crate::{gen, DEMO, INPUT};
+    use aoc_common::InputSource;
+
+    #[test]
+    fn stream_matches_find_marker() {
+        let generated = InputSource::text(gen::generate(3, 10_000));
+        for input in [DEMO, INPUT, generated] {
+            for size in [4, 14] {
+                let reader = input.reader().unwrap();
+                assert_eq!(
+                    crate::markers(&input, size).unwrap(),
+                    markers(reader, size).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn no_marker() {
+        let Err(Error::Parse(err)) = markers("abcd\r\naabb".as_bytes(), 4) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(2, err.line);
+    }
+}
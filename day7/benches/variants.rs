@@ -0,0 +1,23 @@
+// cargo bench -p day7, report in target/criterion/report/index.html
+// every variant reads the input itself, so they all pay for the same copy
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day7::others_solution::{fasterthanli::naive_tree, hashmap_sol};
+use day7::{small_dirs_total, INPUT};
+
+fn small_dirs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day7/small_dirs");
+    group.bench_function("Rc<RefCell<Node>>", |b| {
+        b.iter(|| small_dirs_total(black_box(&INPUT)).unwrap())
+    });
+    group.bench_function("hashmap_sol", |b| {
+        b.iter(|| hashmap_sol::part_1(&black_box(&INPUT).read().unwrap()))
+    });
+    group.bench_function("naive_tree", |b| {
+        b.iter(|| naive_tree::small_dirs_total(&black_box(&INPUT).read().unwrap()).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, small_dirs);
+criterion_main!(benches);
@@ -0,0 +1,139 @@
+//! Seeded synthetic inputs, for stress testing the solvers.
+
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const TOTAL_SPACE: u64 = 70_000_000;
+const NEEDED_SPACE: u64 = 30_000_000;
+
+#[derive(Default)]
+struct Dir {
+    dirs: Vec<(String, Dir)>,
+    files: Vec<(String, u64)>,
+}
+
+/// Terminal transcript exploring a random tree of `size` files and directories.
+///
+/// Like the puzzle, the disk is too full for the update (given a file at
+/// all), and every directory is listed exactly once. Files being at least 1
+/// big, there are at most `TOTAL_SPACE` of them, the rest become directories.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    // paths to every dir, as child indices from the root
+    let mut dirs: Vec<Vec<usize>> = vec![vec![]];
+    let mut root = Dir::default();
+    let mut names: Vec<HashSet<String>> = vec![HashSet::new()];
+    let mut files: u64 = 0;
+
+    for _ in 0..size {
+        let parent = rng.gen_range(0..dirs.len());
+        let name = unique_name(&mut rng, &mut names[parent]);
+        let path = dirs[parent].clone();
+        let dir = path.iter().fold(&mut root, |dir, &i| &mut dir.dirs[i].1);
+
+        if rng.gen_bool(0.3) || files == TOTAL_SPACE {
+            dir.dirs.push((name, Dir::default()));
+            dirs.push([path, vec![dir.dirs.len() - 1]].concat());
+            names.push(HashSet::new());
+        } else {
+            // sizes are spread out below
+            dir.files.push((name, 0));
+            files += 1;
+        }
+    }
+
+    // used space between what the update needs and the whole disk, every
+    // file getting 1 and a share of the rest by weight
+    let used = rng.gen_range((TOTAL_SPACE - NEEDED_SPACE + 1).max(files)..=TOTAL_SPACE);
+    let spare = used - files;
+    let mut weights: Vec<u64> = (0..files).map(|_| rng.gen_range(1..=1000)).collect();
+    let total_weight: u64 = weights.iter().sum();
+    let mut given = 0;
+    for weight in weights.iter_mut() {
+        let share = spare * *weight / total_weight;
+        given += share;
+        *weight = 1 + share;
+    }
+    // what rounding down left over, less than a byte per file
+    for weight in weights.iter_mut().take((spare - given) as usize) {
+        *weight += 1;
+    }
+    let mut sizes = weights.into_iter();
+    set_sizes(&mut root, &mut sizes);
+
+    let mut input = String::from("$ cd /\n");
+    transcript(&root, &mut input);
+    input
+}
+
+fn unique_name(rng: &mut StdRng, taken: &mut HashSet<String>) -> String {
+    loop {
+        let len = rng.gen_range(1..=8);
+        let mut name: String = (0..len).map(|_| rng.gen_range('a'..='z')).collect();
+        if rng.gen_bool(0.5) {
+            name.push('.');
+            name.extend((0..3).map(|_| rng.gen_range('a'..='z')));
+        }
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+fn set_sizes(dir: &mut Dir, sizes: &mut impl Iterator<Item = u64>) {
+    for (_, size) in dir.files.iter_mut() {
+        *size = sizes.next().unwrap_or(1);
+    }
+    for (_, child) in dir.dirs.iter_mut() {
+        set_sizes(child, sizes);
+    }
+}
+
+// `ls` the current dir, then visit every child dir and come back up
+fn transcript(dir: &Dir, input: &mut String) {
+    input.push_str("$ ls\n");
+    for (name, _) in &dir.dirs {
+        input.push_str(&format!("dir {name}\n"));
+    }
+    for (name, size) in &dir.files {
+        input.push_str(&format!("{size} {name}\n"));
+    }
+    for (name, child) in &dir.dirs {
+        input.push_str(&format!("$ cd {name}\n"));
+        transcript(child, input);
+        input.push_str("$ cd ..\n");
+    }
+}
+
+#[cfg(test)]
+mod test_gen {
+    use super::*;
+    use crate::others_solution::{fasterthanli::naive_tree, hashmap_sol};
+    use crate::{dir_to_delete_size, small_dirs_total};
+    use aoc_common::InputSource;
+
+    #[test]
+    fn generate_ok() {
+        let input = generate(7, 500);
+        assert_eq!(input, generate(7, 500));
+
+        let source = InputSource::text(input.clone());
+        let small = small_dirs_total(&source).unwrap();
+        assert_eq!(small, hashmap_sol::part_1(&input));
+        assert_eq!(small, naive_tree::small_dirs_total(&input).unwrap());
+
+        for (seed, size) in [(1, 1), (2, 3), (3, 2000)] {
+            let used: u64 = generate(seed, size)
+                .lines()
+                .filter_map(|line| line.split_once(' ')?.0.parse::<u64>().ok())
+                .sum();
+            assert!(used == 0 || (TOTAL_SPACE - NEEDED_SPACE < used && used <= TOTAL_SPACE));
+        }
+
+        let delete = dir_to_delete_size(&source).unwrap();
+        assert_eq!(delete, naive_tree::dir_to_delete_size(&input).unwrap());
+    }
+}
@@ -2,7 +2,15 @@
 
 
 
-// mod others_solution;
-mod my_solution;
+pub mod gen;
+pub mod stream;
+pub mod others_solution;
+pub mod my_solution;
 
-// use my_solution::Node;
\ No newline at end of file
+pub use my_solution::sol1::{dir_to_delete_size, small_dirs_total};
+
+use aoc_common::InputSource;
+
+pub const DAY: u8 = 7;
+pub const INPUT: InputSource = InputSource::embedded(include_str!("../input.txt"));
+pub const DEMO: InputSource = InputSource::embedded(include_str!("../demo.txt"));
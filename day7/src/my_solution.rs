@@ -7,4 +7,4 @@
 // Find what is needed from data
 
 pub mod utils;
-mod sol1;
\ No newline at end of file
+pub mod sol1;
\ No newline at end of file
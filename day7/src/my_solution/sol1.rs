@@ -4,10 +4,13 @@ use std::{
     cell::RefCell,
     fmt::Display,
     ops::Deref,
-    process::Command,
     rc::{Rc, Weak},
 };
 
+use aoc_common::InputLine;
+
+use crate::DAY;
+
 pub use super::utils::Cmd::*;
 pub use super::utils::Entry::*;
 use super::utils::*;
@@ -140,6 +143,31 @@ impl Node {
         self.size == 0
     }
 
+    // in u64, as a few big files add up to more than a u32
+    fn total_size(&self) -> u64 {
+        let children_size: u64 = self
+            .children
+            .iter()
+            .flatten()
+            .map(|child| child.borrow().total_size())
+            .sum();
+        u64::from(self.size) + children_size
+    }
+
+    // every directory below (and including) the given one
+    fn all_dirs(noderef: NodeRef) -> Vec<NodeRef> {
+        let mut dirs = Vec::new();
+        let mut stack = vec![noderef];
+
+        while let Some(curr) = stack.pop() {
+            if let Some(children) = curr.borrow().children.as_ref() {
+                stack.extend(children.iter().filter(|c| c.borrow().is_dir()).cloned());
+            }
+            dirs.push(curr);
+        }
+        dirs
+    }
+
     fn ls(noderef: NodeRef) -> Option<NodeRef> {
         if let Some(children) = noderef.clone().borrow().children.clone() {
             for child in children {
@@ -153,7 +181,7 @@ impl Node {
     }
 
     fn cd(path: &str, curr: NodeRef) -> Option<NodeRef> {
-        let children = curr.clone().borrow().children.clone().unwrap();
+        let children = curr.borrow().children.clone()?;
         for child in children {
             if child.borrow().name == path {
                 return Some(child.clone());
@@ -163,25 +191,19 @@ impl Node {
     }
 
     fn goto_parent(curr: NodeRef) -> Option<NodeRef> {
-        if let Some(parent) = curr.clone().borrow().parent.clone() {
-            parent.upgrade()
-        } else {
-            panic!("Unable to goto Parent from curr");
-            None
-        }
+        // the root has no parent
+        curr.borrow().parent.clone().and_then(|parent| parent.upgrade())
     }
 
     fn goto_root(curr: NodeRef) -> Option<NodeRef> {
+        let mut curr = curr;
         loop {
-            let curr = Node::goto_parent(curr.clone());
-            if curr.is_some() {
-                continue;
-            } else {
-                break curr;
+            let parent = curr.borrow().parent.clone().and_then(|p| p.upgrade());
+            match parent {
+                Some(parent) => curr = parent,
+                None => break Some(curr),
             }
         }
-        // while Node::goto_parent(curr.clone()).is_some() {}
-        // Some(curr)
     }
 }
 
@@ -201,25 +223,22 @@ fn process_line(line: Line, curr: NodeRef) -> Option<NodeRef> {
         Command(Cmd::Ls) => Some(curr.clone()),
         Command(Cmd::Cd(GoTo::DirName(dirname))) => Node::cd(&dirname, curr),
         Command(Cmd::Cd(GoTo::Parent)) => Node::goto_parent(curr),
-        // Command(Cmd::Cd(GoTo::Root)) => Node::goto_root(curr),
+        Command(Cmd::Cd(GoTo::Root)) => Node::goto_root(curr),
         Entry(DirDescription(name)) => Some(Node::create_dir_and_update_parent(curr, &name)),
         Entry(FileDescription { name, size }) => {
             Some(Node::create_file_and_update_parent(curr, &name, size))
         }
-        line => {
-            dbg!(line);
-            None
-        }
     }
 }
 
-fn handle_terminal_data(input_str: Vec<String>) -> NodeRef {
+fn handle_terminal_data(input_str: Vec<String>) -> aoc_common::Result<NodeRef> {
     let mut curr = Node::root();
     let root = curr.clone();
     let mut dirs: Vec<NodeRef> = vec![root.clone()];
 
-    for line in input_str.iter().skip(1) {
-        let terminal_line = parse_line(line);
+    for (index, text) in input_str.iter().enumerate().skip(1) {
+        let line = InputLine::new(DAY, index + 1, text);
+        let terminal_line = parse_line(line)?;
         // dbg!(terminal_line.clone());
         curr = {
             match process_line(terminal_line.clone(), curr.clone()) {
@@ -230,11 +249,44 @@ fn handle_terminal_data(input_str: Vec<String>) -> NodeRef {
                         curr
                     }
                 }
-                None => panic!("Not able handle terminal line"),
+                None => return Err(line.error("no such directory")),
             }
         };
     }
-    root.clone()
+    Ok(root.clone())
+}
+
+pub(crate) const SMALL_DIR_LIMIT: u32 = 100_000;
+pub(crate) const TOTAL_SPACE: u32 = 70_000_000;
+pub(crate) const NEEDED_SPACE: u32 = 30_000_000;
+
+/// sum of the sizes of all directories with a total size of at most 100000
+pub fn small_dirs_total(input: &aoc_common::InputSource) -> aoc_common::Result<u64> {
+    let root = handle_terminal_data(read_terminal(input)?)?;
+    Ok(Node::all_dirs(root)
+        .iter()
+        .map(|dir| dir.borrow().total_size())
+        .filter(|&size| size <= u64::from(SMALL_DIR_LIMIT))
+        .sum())
+}
+
+/// size of the smallest directory that frees up enough space for the update
+pub fn dir_to_delete_size(input: &aoc_common::InputSource) -> aoc_common::Result<u64> {
+    let root = handle_terminal_data(read_terminal(input)?)?;
+    let used_space = root.borrow().total_size();
+    let free_space = u64::from(TOTAL_SPACE)
+        .checked_sub(used_space)
+        .ok_or_else(|| {
+            aoc_common::Error::invalid(format!("{used_space} used on a disk of {TOTAL_SPACE}"))
+        })?;
+    let to_free = u64::from(NEEDED_SPACE).saturating_sub(free_space);
+
+    Ok(Node::all_dirs(root)
+        .iter()
+        .map(|dir| dir.borrow().total_size())
+        .filter(|&size| size >= to_free)
+        .min()
+        .unwrap_or_default())
 }
 
 #[cfg(test)]
@@ -242,7 +294,7 @@ mod test_sol1 {
     use crate::my_solution::utils::{parse_line, read_terminal};
 
     use super::*;
-    const INPUT: &str = "/home/user/dev/tutorial/adventOfCode/2022/day7/demo.txt";
+    use crate::DEMO as INPUT;
 
     #[test]
     fn create_node() {
@@ -253,7 +305,7 @@ mod test_sol1 {
     #[test]
     fn test_is_dir() {
         let root = Node::root();
-        let file = Node::create_file_and_update_parent(root.clone(), "ab", 999);
+        let file = Node::new_file(root.clone(), "ab".to_string(), 999);
         assert!(root.borrow().is_dir());
         assert!(!file.borrow().is_dir());
     }
@@ -272,10 +324,13 @@ mod test_sol1 {
         let root = Node::root();
         Node::create_file_and_update_parent(root.clone(), "ab", 102);
         Node::create_file_and_update_parent(root.clone(), "cd", 102);
-        let curr1 = Node::create_dir_and_update_parent(root.clone(), "x");
-        let curr = Node::create_dir_and_update_parent(curr1.clone(), "y");
+        Node::create_dir_and_update_parent(root.clone(), "x");
+        let curr1 = Node::cd("x", root.clone()).unwrap();
+        Node::create_dir_and_update_parent(curr1.clone(), "y");
+        let curr = Node::cd("y", curr1.clone()).unwrap();
         // Node::create_file_and_update_parent(curr.clone(), "yd", 102);
-        dbg!(Node::goto_parent(curr));
+        let parent = Node::goto_parent(curr).unwrap();
+        assert!(Rc::ptr_eq(&curr1, &parent));
     }
 
     #[test]
@@ -283,10 +338,13 @@ mod test_sol1 {
         let root = Node::root();
         Node::create_file_and_update_parent(root.clone(), "ab", 102);
         Node::create_file_and_update_parent(root.clone(), "cd", 102);
-        let curr = Node::create_dir_and_update_parent(root.clone(), "x");
-        let curr = Node::create_dir_and_update_parent(curr.clone(), "y");
+        Node::create_dir_and_update_parent(root.clone(), "x");
+        let curr = Node::cd("x", root.clone()).unwrap();
+        Node::create_dir_and_update_parent(curr.clone(), "y");
+        let curr = Node::cd("y", curr).unwrap();
         Node::create_file_and_update_parent(curr.clone(), "yd", 102);
-        dbg!(Node::goto_root(curr));
+        let top = Node::goto_root(curr).unwrap();
+        assert!(Rc::ptr_eq(&root, &top));
     }
 
     #[test]
@@ -317,13 +375,13 @@ mod test_sol1 {
 
     #[test]
     fn handle_terminal_ok() {
-        let input_str = read_terminal(INPUT);
+        let input_str = read_terminal(&INPUT).unwrap();
         let mut curr = Node::root();
         let root = curr.clone();
         let mut dirs: Vec<NodeRef> = vec![root.clone()];
 
-        for line in input_str.iter().skip(1) {
-            let terminal_line = parse_line(line);
+        for (index, text) in input_str.iter().enumerate().skip(1) {
+            let terminal_line = parse_line(InputLine::new(DAY, index + 1, text)).unwrap();
             // dbg!(curr.clone().borrow().name.clone());
             // dbg!(curr.clone());
             dbg!(terminal_line.clone());
@@ -345,9 +403,39 @@ mod test_sol1 {
 
     #[test]
     fn solution_part1() {
-        let input_str = read_terminal(INPUT);
-        let root = handle_terminal_data(input_str);
+        let input_str = read_terminal(&INPUT).unwrap();
+        let root = handle_terminal_data(input_str).unwrap();
+        assert_eq!(48381165, root.borrow().total_size());
+        assert_eq!(95437, small_dirs_total(&INPUT).unwrap());
+    }
+
+    #[test]
+    fn solution_part2() {
+        assert_eq!(24933642, dir_to_delete_size(&INPUT).unwrap());
+    }
+
+    #[test]
+    fn big_files() {
+        let input = aoc_common::InputSource::text("$ cd /\n$ ls\n3000000000 a\n3000000000 b\n");
+        assert_eq!(0, small_dirs_total(&input).unwrap());
+        // more than the disk
+        assert!(dir_to_delete_size(&input).is_err());
+
+        let root = handle_terminal_data(read_terminal(&input).unwrap()).unwrap();
+        assert_eq!(6_000_000_000, root.borrow().total_size());
+    }
+
+    #[test]
+    fn unknown_dir() {
+        let input: Vec<String> = ["$ cd /", "$ ls", "dir a", "$ cd b"]
+            .map(String::from)
+            .to_vec();
+        let Err(aoc_common::Error::Parse(err)) = handle_terminal_data(input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((4, "$ cd b"), (err.line, err.text.as_str()));
 
-        
+        let input: Vec<String> = ["$ cd /", "$ cd .."].map(String::from).to_vec();
+        assert!(handle_terminal_data(input).is_err());
     }
 }
@@ -1,3 +1,5 @@
+use aoc_common::InputLine;
+
 
 #[derive(Debug, Clone)]
 pub enum Line {
@@ -28,52 +30,62 @@ pub use GoTo::*;
 pub use Cmd::*;
 pub use Entry::*;
 
-pub fn parse_line(line: &str) -> Line {
-    if line.contains("$") && line.contains("cd") {
-        let content: Vec<&str> = line.split(" ").collect();
-        let detail = match content[2] {
-            "/" => return Line::Command(Cd(GoTo::Root)),
-            ".." => return Line::Command(Cd(GoTo::Parent)),
-            dirname => Line::Command(Cd(GoTo::DirName(dirname.to_string()))),
-        };
-        return detail;
-    }
-    if line.contains("$") && line.contains("ls") {
-        return Line::Command(Ls);
+pub fn parse_line(line: InputLine) -> aoc_common::Result<Line> {
+    let content: Vec<&str> = line.text.split(' ').collect();
+    match content[..] {
+        ["$", "cd", "/"] => Ok(Line::Command(Cd(GoTo::Root))),
+        ["$", "cd", ".."] => Ok(Line::Command(Cd(GoTo::Parent))),
+        ["$", "cd", dirname] => Ok(Line::Command(Cd(GoTo::DirName(dirname.to_string())))),
+        ["$", "ls"] => Ok(Line::Command(Ls)),
+        ["$", command, ..] => Err(line.error_at(command, "unknown command")),
+        ["dir", name] => Ok(Line::Entry(DirDescription(name.to_string()))),
+        [size, name] => Ok(Line::Entry(FileDescription {
+            name: name.to_string(),
+            size: size
+                .parse::<u32>()
+                .map_err(|e| line.error_at(size, format!("not a file size ({e})")))?,
+        })),
+        _ => Err(line.error("expected a command or a directory entry")),
     }
-    if line.contains("dir") {
-        let content: Vec<&str> = line.split(" ").collect();
-        return Line::Entry(DirDescription(content[1].to_string()));
-    } else if line.is_ascii() {
-        let content: Vec<&str> = line.split(" ").collect();
-        return Line::Entry(FileDescription {
-            name: content[1].to_string(),
-            size: content[0].parse::<u32>().expect("Parsing of file size"),
-        });
-    } else{
-        panic!("Not able to parse line");
-    }
-
 }
 
-pub fn read_terminal(input: &str) -> Vec<String> {
-    let input_str = std::fs::read_to_string(input).expect("Able to read input file");
-    let terminal_lines: Vec<String> = input_str.split("\n").map(|s| s.to_string()).collect();
-    terminal_lines
+pub fn read_terminal(input: &aoc_common::InputSource) -> aoc_common::Result<Vec<String>> {
+    input.read_lines()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    const INPUT: &str = "/home/user/dev/tutorial/adventOfCode/2022/day7/demo.txt";
+    use crate::DEMO as INPUT;
 
     #[test]
     fn test_reading_terminal() {
-        let input = std::fs::read_to_string(INPUT).expect("Able to read input file");
+        let input = INPUT.read().expect("Able to read input file");
         let terminal_lines: Vec<&str> = input.split("\n").collect();
         dbg!(terminal_lines);
     }
 
     #[test]
-    fn test_determine_which_command() {}
+    fn test_determine_which_command() {
+        let line = |text| parse_line(InputLine::new(crate::DAY, 1, text));
+        assert!(matches!(line("$ cd /"), Ok(Line::Command(Cd(Root)))));
+        assert!(matches!(line("$ cd .."), Ok(Line::Command(Cd(Parent)))));
+        assert!(matches!(line("$ ls"), Ok(Line::Command(Ls))));
+        assert!(matches!(line("dir a"), Ok(Line::Entry(DirDescription(_)))));
+        assert!(matches!(
+            line("14848514 b.txt"),
+            Ok(Line::Entry(FileDescription { size: 14848514, .. }))
+        ));
+    }
+
+    #[test]
+    fn bad_lines() {
+        let line = |text| match parse_line(InputLine::new(crate::DAY, 1, text)) {
+            Err(aoc_common::Error::Parse(err)) => (err.column, err.text),
+            other => panic!("expected a parse error, got {other:?}"),
+        };
+        assert_eq!((3, "rm".to_string()), line("$ rm -rf"));
+        assert_eq!((1, "12k".to_string()), line("12k b.txt"));
+        assert_eq!((1, "".to_string()), line(""));
+    }
 }
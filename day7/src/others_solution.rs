@@ -1,2 +1,2 @@
-mod fasterthanli;
-mod hashmap_sol;
+pub mod fasterthanli;
+pub mod hashmap_sol;
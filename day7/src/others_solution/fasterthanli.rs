@@ -93,7 +93,7 @@ mod parsing_input {
         };
 
         fn main() {
-            let lines = include_str!("../demo.txt")
+            let lines = include_str!("../../demo.txt")
                 .lines()
                 .map(|l| all_consuming(parse_line)(l).finish().unwrap().1);
 
@@ -116,7 +116,7 @@ mod parsing_input {
     // }
 }
 
-mod naive_tree {
+pub mod naive_tree {
     use super::*;
     use indexmap::IndexMap;
     use std::cell::RefCell;
@@ -202,88 +202,90 @@ mod naive_tree {
         )
     }
 
-    #[test]
-    fn test_solution() {
+    fn build_tree(input: &str) -> aoc_common::Result<NodeHandle> {
         use super::parsing_input::*;
-        use camino::Utf8PathBuf;
-        use nom::{
-            branch::alt,
-            bytes::complete::{tag, take_while1},
-            combinator::{all_consuming, map},
-            sequence::{preceded, separated_pair},
-            Finish, IResult,
-        };
-
-        fn main() {
-            let lines = include_str!("../input.txt")
-                .lines()
-                .map(|l| all_consuming(parse_line)(l).finish().unwrap().1);
-
-            let root = Rc::new(RefCell::new(Node::default()));
-            let mut node = root.clone();
-
-            for line in lines {
-                println!("{line:?}");
-                match line {
-                    Line::Command(cmd) => match cmd {
-                        Command::Ls => {
-                            // just ignore those
+        use nom::{combinator::all_consuming, Finish};
+
+        let root = Rc::new(RefCell::new(Node::default()));
+        let mut node = root.clone();
+
+        for l in input.lines() {
+            let line = all_consuming(parse_line)(l)
+                .finish()
+                .map_err(|_| aoc_common::Error::invalid(format!("can't parse {l:?}")))?
+                .1;
+            match line {
+                Line::Command(cmd) => match cmd {
+                    Command::Ls => {
+                        // just ignore those
+                    }
+                    Command::Cd(path) => match path.as_str() {
+                        "/" => {
+                            // ignore, we're already there
                         }
-                        Command::Cd(path) => match path.as_str() {
-                            "/" => {
-                                // ignore, we're already there
-                            }
-                            ".." => {
-                                let parent = node.borrow().parent.clone().unwrap();
-                                node = parent;
-                            }
-                            _ => {
-                                let child =
-                                    node.borrow_mut().children.entry(path).or_default().clone();
-                                node = child;
-                            }
-                        },
-                    },
-                    Line::Entry(entry) => match entry {
-                        Entry::Dir(dir) => {
-                            let entry = node.borrow_mut().children.entry(dir).or_default().clone();
-                            entry.borrow_mut().parent = Some(node.clone());
+                        ".." => {
+                            let parent = node.borrow().parent.clone().ok_or_else(|| {
+                                aoc_common::Error::invalid("can't cd .. from the root")
+                            })?;
+                            node = parent;
                         }
-                        Entry::File(size, file) => {
-                            let entry = node.borrow_mut().children.entry(file).or_default().clone();
-                            entry.borrow_mut().size = size as usize;
-                            entry.borrow_mut().parent = Some(node.clone());
+                        _ => {
+                            let child = node.borrow_mut().children.entry(path).or_default().clone();
+                            node = child;
                         }
                     },
-                }
+                },
+                Line::Entry(entry) => match entry {
+                    Entry::Dir(dir) => {
+                        let entry = node.borrow_mut().children.entry(dir).or_default().clone();
+                        entry.borrow_mut().parent = Some(node.clone());
+                    }
+                    Entry::File(size, file) => {
+                        let entry = node.borrow_mut().children.entry(file).or_default().clone();
+                        entry.borrow_mut().size = size as usize;
+                        entry.borrow_mut().parent = Some(node.clone());
+                    }
+                },
             }
-            // println!("{root:#?}");
-
-            let sum = all_dirs(root.clone())
-                .map(|d| d.borrow().total_size())
-                .filter(|&s| s <= 100_000)
-                .inspect(|s| {
-                    dbg!(s);
-                })
-                .sum::<u64>();
-            dbg!(sum);
-
-            let total_space = 70000000_u64;
-            let used_space = root.borrow().total_size();
-            let free_space = total_space.checked_sub(dbg!(used_space)).unwrap();
-            let needed_free_space = 30000000_u64;
-            let minimum_space_to_free = needed_free_space.checked_sub(free_space).unwrap();
-
-            let removed_dir_size = all_dirs(root)
-                .map(|d| d.borrow().total_size())
-                .filter(|&s| s >= minimum_space_to_free)
-                .inspect(|s| {
-                    dbg!(s);
-                })
-                .min();
-            dbg!(removed_dir_size);
         }
+        // println!("{root:#?}");
+        Ok(root)
+    }
 
-        main()
+    pub fn small_dirs_total(input: &str) -> aoc_common::Result<u64> {
+        let root = build_tree(input)?;
+        let sum = all_dirs(root)
+            .map(|d| d.borrow().total_size())
+            .filter(|&s| s <= 100_000)
+            .sum::<u64>();
+        Ok(sum)
+    }
+
+    pub fn dir_to_delete_size(input: &str) -> aoc_common::Result<u64> {
+        let root = build_tree(input)?;
+
+        let total_space = 70000000_u64;
+        let used_space = root.borrow().total_size();
+        let free_space = total_space.saturating_sub(used_space);
+        let needed_free_space = 30000000_u64;
+        let minimum_space_to_free = needed_free_space.saturating_sub(free_space);
+
+        let removed_dir_size = all_dirs(root)
+            .map(|d| d.borrow().total_size())
+            .filter(|&s| s >= minimum_space_to_free)
+            .min();
+        Ok(removed_dir_size.unwrap_or_default())
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[test]
+        fn test_solution() {
+            let input = include_str!("../../input.txt");
+            assert_eq!(1908462, small_dirs_total(input).unwrap());
+            assert_eq!(3979145, dir_to_delete_size(input).unwrap());
+        }
     }
 }
@@ -9,9 +9,7 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
-#[test]
-pub fn part_1(){
-    let input = std::fs::read_to_string("input.txt").unwrap();
+pub fn part_1(input: &str) -> u64 {
     let mut sizes = HashMap::new();
     let mut affected = Vec::new();
 
@@ -29,16 +27,30 @@ pub fn part_1(){
                 affected.push(name);
             }
             [size, _name] => {
-                let size: u32 = size.parse().unwrap();
+                // anything else isn't a file entry
+                let Ok(size) = size.parse::<u32>() else {
+                    continue;
+                };
                 for idx in 0..affected.len() {
                     let path = PathBuf::from_iter(&affected[..=idx]);
-                    *sizes.entry(path).or_insert(0) += size;
+                    *sizes.entry(path).or_insert(0) += u64::from(size);
                 }
             }
             _ => {}
         };
     }
 
-    let result: u32 = sizes.into_values().filter(|size| *size <= 100_000).sum();
-    dbg!(&result);
+    let result: u64 = sizes.into_values().filter(|size| *size <= 100_000).sum();
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_part_1() {
+        assert_eq!(95437, part_1(include_str!("../../demo.txt")));
+        assert_eq!(1908462, part_1(include_str!("../../input.txt")));
+    }
 }
@@ -0,0 +1,114 @@
+//! Solvers reading the terminal output line by line.
+//!
+//! Only the sizes of the directories on the current path are kept around
+//! (plus one number per directory for part 2), never the transcript or the
+//! tree itself. Like the puzzle, every directory must be entered only once.
+
+use std::io::BufRead;
+
+use aoc_common::{for_each_line, Error, Result};
+
+use crate::my_solution::sol1::{NEEDED_SPACE, SMALL_DIR_LIMIT, TOTAL_SPACE};
+use crate::my_solution::utils::*;
+use crate::DAY;
+
+// calls `f` with the total size of each directory once it's been left, the
+// root last
+fn for_each_dir(reader: impl BufRead, mut f: impl FnMut(u64)) -> Result<()> {
+    // sizes so far of the root and the directories below it, down to the
+    // current one
+    let mut path: Vec<u64> = vec![0];
+    let mut leave = |path: &mut Vec<u64>| {
+        let size = path.pop().unwrap();
+        *path.last_mut().unwrap() += size;
+        f(size);
+    };
+
+    for_each_line(DAY, reader, |line| {
+        match parse_line(line)? {
+            Line::Command(Cd(Root)) => {
+                while path.len() > 1 {
+                    leave(&mut path);
+                }
+            }
+            Line::Command(Cd(Parent)) => {
+                if path.len() == 1 {
+                    return Err(line.error("the root has no parent"));
+                }
+                leave(&mut path);
+            }
+            Line::Command(Cd(DirName(_))) => path.push(0),
+            Line::Command(Ls) | Line::Entry(DirDescription(_)) => {}
+            Line::Entry(FileDescription { size, .. }) => {
+                *path.last_mut().unwrap() += u64::from(size);
+            }
+        }
+        Ok(())
+    })?;
+
+    while path.len() > 1 {
+        leave(&mut path);
+    }
+    f(path[0]);
+    Ok(())
+}
+
+/// sum of the sizes of all directories with a total size of at most 100000
+pub fn small_dirs_total(reader: impl BufRead) -> Result<u64> {
+    let mut total = 0;
+    for_each_dir(reader, |size| {
+        if size <= u64::from(SMALL_DIR_LIMIT) {
+            total += size;
+        }
+    })?;
+    Ok(total)
+}
+
+/// size of the smallest directory that frees up enough space for the update
+pub fn dir_to_delete_size(reader: impl BufRead) -> Result<u64> {
+    let mut sizes = Vec::new();
+    for_each_dir(reader, |size| sizes.push(size))?;
+
+    // the root comes last
+    let used_space = sizes.last().copied().unwrap_or_default();
+    let free_space = u64::from(TOTAL_SPACE)
+        .checked_sub(used_space)
+        .ok_or_else(|| Error::invalid(format!("{used_space} used on a disk of {TOTAL_SPACE}")))?;
+    let to_free = u64::from(NEEDED_SPACE).saturating_sub(free_space);
+
+    Ok(sizes
+        .into_iter()
+        .filter(|&size| size >= to_free)
+        .min()
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod test_stream {
+    use super::*;
+    use crate::{gen, DEMO, INPUT};
+    use aoc_common::InputSource;
+
+    #[test]
+    fn stream_matches_sol1() {
+        let generated = InputSource::text(gen::generate(3, 2000));
+        for input in [DEMO, INPUT, generated] {
+            let reader = input.reader().unwrap();
+            assert_eq!(
+                crate::small_dirs_total(&input).unwrap(),
+                small_dirs_total(reader).unwrap()
+            );
+            let reader = input.reader().unwrap();
+            assert_eq!(
+                crate::dir_to_delete_size(&input).unwrap(),
+                dir_to_delete_size(reader).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn cd_above_root() {
+        let err = small_dirs_total("$ cd /\n$ cd ..\n".as_bytes()).unwrap_err();
+        assert!(matches!(err, Error::Parse(_)));
+    }
+}